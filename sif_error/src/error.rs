@@ -6,10 +6,12 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/11/27
-//  @date 2019/07/09
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
+use alloc::string::String;
+#[cfg(feature = "std")]
 use std::error::Error as StdError;
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
@@ -22,12 +24,13 @@ pub enum Error {
     InvalidArgument(String),
 }
 // ============================================================================
-impl ::std::fmt::Display for Error {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        <Self as ::std::fmt::Debug>::fmt(self, f)
+impl ::core::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        <Self as ::core::fmt::Debug>::fmt(self, f)
     }
 }
 // ============================================================================
+#[cfg(feature = "std")]
 impl StdError for Error {
     // ========================================================================
     fn description(&self) -> &str {
@@ -47,7 +50,7 @@ impl StdError for Error {
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// type Result
-pub type Result<T> = ::std::result::Result<T, Error>;
+pub type Result<T> = ::core::result::Result<T, Error>;
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 #[cfg(test)]
@@ -6,7 +6,7 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/06
-//  @date 2018/05/11
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
@@ -25,6 +25,9 @@ macro_rules! unwrap {
     };
 }
 // ----------------------------------------------------------------------------
+#[doc(hidden)]
+pub use alloc::format as __format;
+// ----------------------------------------------------------------------------
 /// unwrap!
 #[cfg(debug_assertions)]
 #[macro_export]
@@ -37,7 +40,7 @@ macro_rules! unwrap {
         unwrap!($e, "{}", $msg)
     };
     ($e:expr, $fmt:expr, $($args:tt)+)  => {
-        ($e).expect(&format!(concat!(module_path!(), ": ", file!(),
+        ($e).expect(&$crate::__format!(concat!(module_path!(), ": ", file!(),
                                      "(", line!(), "): ", $fmt), $($args)+))
     };
 }
@@ -6,13 +6,15 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/19
-//  @date 2019/07/14
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 use uuid::Uuid;
 // ----------------------------------------------------------------------------
-use sif_math::{Float, Matrix4x4, Vector4};
+use sif_math::{Float, Matrix4x4};
+use sif_nursery::geometry::{ClipDepth, Frustum};
+use sif_three::Node;
 // ----------------------------------------------------------------------------
 use super::{Error, Result};
 // ////////////////////////////////////////////////////////////////////////////
@@ -135,28 +137,9 @@ where
     // ========================================================================
     /// frustum
     pub fn frustum(near: V, far: V, focus: V, aspect: V) -> Matrix4x4<V> {
-        let c = V::one() / (near - far);
-        Matrix4x4::from([
-            Vector4::from_no_clean([focus, V::zero(), V::zero(), V::zero()]),
-            Vector4::from_no_clean([
-                V::zero(),
-                focus / aspect,
-                V::zero(),
-                V::zero(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                V::zero(),
-                (near + far) * c,
-                -V::one(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                V::zero(),
-                V::from(2).unwrap() * near * far * c,
-                V::zero(),
-            ]),
-        ])
+        let right = near / focus;
+        let top = right * aspect;
+        Matrix4x4::new_frustum(-right, right, -top, top, near, far)
     }
     // ------------------------------------------------------------------------
     /// inverse_frustum
@@ -166,64 +149,40 @@ where
         focus: V,
         aspect: V,
     ) -> Matrix4x4<V> {
-        let c = V::one() / (V::from(2).unwrap() * far * near);
-        Matrix4x4::from([
-            Vector4::from_no_clean([
-                V::one() / focus,
-                V::zero(),
-                V::zero(),
-                V::zero(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                aspect / focus,
-                V::zero(),
-                V::zero(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                V::zero(),
-                V::zero(),
-                -V::one(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                V::zero(),
-                (near - far) * c,
-                (near + far) * c,
-            ]),
-        ])
+        let right = near / focus;
+        let top = right * aspect;
+        Matrix4x4::new_inverse_frustum(-right, right, -top, top, near, far)
     }
     // ========================================================================
     /// ortho
     pub fn ortho(near: V, far: V, width: V, height: V) -> Matrix4x4<V> {
-        let c = V::one() / (near - far);
-        Matrix4x4::from([
-            Vector4::from_no_clean([
-                V::from(2).unwrap() / width,
-                V::zero(),
-                V::zero(),
-                V::zero(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                V::from(2).unwrap() / height,
-                V::zero(),
-                V::zero(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                V::zero(),
-                V::from(2).unwrap() * c,
-                V::zero(),
-            ]),
-            Vector4::from_no_clean([
-                V::zero(),
-                V::zero(),
-                (near + far) * c,
-                V::one(),
-            ]),
-        ])
+        let two = V::from(2).unwrap();
+        Matrix4x4::new_ortho(
+            -width / two,
+            width / two,
+            -height / two,
+            height / two,
+            near,
+            far,
+        )
+    }
+    // ------------------------------------------------------------------------
+    /// inverse_ortho
+    pub fn inverse_ortho(
+        near: V,
+        far: V,
+        width: V,
+        height: V,
+    ) -> Matrix4x4<V> {
+        let two = V::from(2).unwrap();
+        Matrix4x4::new_inverse_ortho(
+            -width / two,
+            width / two,
+            -height / two,
+            height / two,
+            near,
+            far,
+        )
     }
     // ========================================================================
     /// new_frustum
@@ -314,6 +273,28 @@ where
             }
         }
     }
+    // ------------------------------------------------------------------------
+    /// inverse_projection_matrix
+    pub fn inverse_projection_matrix(&self) -> Matrix4x4<V> {
+        match self.camera_type {
+            CameraType::Frustum(focus, aspect) => {
+                Camera::inverse_frustum(self.near, self.far, focus, aspect)
+            }
+            CameraType::Ortho(width, height) => {
+                Camera::inverse_ortho(self.near, self.far, width, height)
+            }
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// view_frustum
+    ///
+    /// The frustum in the world, seen from `node`.
+    pub fn view_frustum(&self, node: &Node<V>) -> Result<Frustum<V>> {
+        Ok(Frustum::from_matrix(
+            &(self.projection_matrix() * *node.as_inverse_matrix()),
+            ClipDepth::NegativeOneToOne,
+        )?)
+    }
     // ========================================================================
     /// focus2alpha
     pub fn focus2alpha(focus: V) -> V {
@@ -325,3 +306,61 @@ where
         V::one() / ::num::Float::tan(alpha / V::from(2).unwrap())
     }
 }
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use sif_math::{Quaternion, Vector3};
+    use sif_nursery::geometry::Containment;
+    use sif_three::TraRotSca;
+    // ========================================================================
+    fn is_identity(m: &Matrix4x4<f64>) -> bool {
+        let i = Matrix4x4::<f64>::new_identity();
+        (0..4).all(|c| (0..4).all(|r| (m[c][r] - i[c][r]).abs() < 1.0e-9))
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn inverse_projection() {
+        let m = Camera::frustum(0.5, 40.0, 1.5, 0.75)
+            * Camera::inverse_frustum(0.5, 40.0, 1.5, 0.75);
+        assert!(is_identity(&m), "{:?}", m);
+        let m = Camera::ortho(0.5, 40.0, 4.0, 3.0)
+            * Camera::inverse_ortho(0.5, 40.0, 4.0, 3.0);
+        assert!(is_identity(&m), "{:?}", m);
+        for camera in &[
+            Camera::new_frustum(Uuid::nil(), "", 1.0, 100.0, 2.0, 0.5),
+            Camera::new_ortho(Uuid::nil(), "", 1.0, 100.0, 8.0, 6.0),
+        ] {
+            let m = camera.inverse_projection_matrix()
+                * camera.projection_matrix();
+            assert!(is_identity(&m), "{:?}", m);
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn view_frustum() {
+        // at `(0, 0, 10)`, looking down `-X`, near `1`, far `21`, 90 degrees
+        let camera = Camera::new_frustum(Uuid::nil(), "", 1.0, 21.0, 1.0, 1.0);
+        let mut node = Node::new(Uuid::nil(), None);
+        {
+            let trs: &mut TraRotSca<f64> = node.as_mut();
+            trs.translate = Vector3::new(0.0, 0.0, 10.0);
+            trs.rotate = Quaternion::from_axis_angle(
+                &Vector3::new(0.0, 1.0, 0.0),
+                ::core::f64::consts::FRAC_PI_2,
+            )
+            .unwrap();
+        }
+        let _ = node.update().unwrap();
+        let f = camera.view_frustum(&node).unwrap();
+        let p = |x: f64, y: f64, z: f64| f.test_point(&Vector3::new(x, y, z));
+        assert_eq!(p(-5.0, 0.0, 10.0), Containment::Inside);
+        assert_eq!(p(-5.0, 4.0, 6.0), Containment::Inside);
+        assert_eq!(p(-0.5, 0.0, 10.0), Containment::Outside);
+        assert_eq!(p(-25.0, 0.0, 10.0), Containment::Outside);
+        assert_eq!(p(5.0, 0.0, 10.0), Containment::Outside);
+        assert_eq!(p(-5.0, 6.0, 10.0), Containment::Outside);
+    }
+}
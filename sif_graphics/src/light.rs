@@ -6,16 +6,16 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/04/24
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 use bitflags::bitflags;
-use gl::types::{GLfloat, GLuint};
+use gl::types::{GLfloat, GLint, GLuint};
 use uuid::Uuid;
 // ----------------------------------------------------------------------------
 use sif_manager::ManagedValue;
-use sif_math::{Float, Vector3};
+use sif_math::{Float, IVector2, Vector3};
 use sif_renderer::{Bind, Program, Texture};
 // ----------------------------------------------------------------------------
 use super::{post::DepthMapParam, Error, Object, Result, Shadow};
@@ -173,7 +173,7 @@ where
     }
     // ========================================================================
     /// as_shadow_size
-    pub fn as_shadow_size(&self) -> &[i32; 2] {
+    pub fn as_shadow_size(&self) -> &IVector2<GLint> {
         if let Some(ref shadow) = self.shadow {
             shadow.size()
         } else {
@@ -6,14 +6,14 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/02/13
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 use gl::types::*;
 // ----------------------------------------------------------------------------
 use sif_manager::ManagedValue;
-use sif_math::{Float, Matrix4x4};
+use sif_math::{Float, IVector2, Matrix4x4};
 use sif_renderer::{
     gl_result, sif_renderer_program_location, Bind, Program, ShaderSrc,
     Texture,
@@ -215,7 +215,7 @@ impl DepthMap {
     }
     // ========================================================================
     /// size
-    pub fn size(&self) -> &[GLint; 2] {
+    pub fn size(&self) -> &IVector2<GLint> {
         self.screen.size()
     }
     // ========================================================================
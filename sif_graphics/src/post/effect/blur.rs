@@ -6,13 +6,14 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/01/19
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 use gl::types::*;
 use num::Float;
 // ----------------------------------------------------------------------------
+use sif_math::IVector2;
 use sif_renderer::{
     gl_result, sif_renderer_program_location, Bind, Frame, Program, ShaderSrc,
     Texture,
@@ -68,7 +69,7 @@ pub struct Blur {
     /// frame
     frame: Frame,
     /// size
-    size: [GLsizei; 2],
+    size: IVector2<GLsizei>,
     /// gaussian
     gaussian: Vec<GLfloat>,
 }
@@ -119,13 +120,13 @@ impl Blur {
             ])?,
             color0,
             frame,
-            size: [width, height],
+            size: IVector2::new(width, height),
             gaussian,
         })
     }
     // ========================================================================
     /// size
-    pub fn size(&self) -> &[GLint; 2] {
+    pub fn size(&self) -> &IVector2<GLint> {
         &self.size
     }
 }
@@ -6,7 +6,7 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/01/17
-//  @date 2018/05/17
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
@@ -14,6 +14,7 @@ use std::result::Result as StdResult;
 // ----------------------------------------------------------------------------
 use gl::types::*;
 // ----------------------------------------------------------------------------
+use sif_math::{IRect, IVector2};
 use sif_renderer::{gl_result, Bind, Frame, Render, Texture};
 // ----------------------------------------------------------------------------
 use super::{Error, Result};
@@ -23,7 +24,7 @@ use super::{Error, Result};
 #[derive(Debug)]
 pub struct Screen {
     /// size
-    size: [GLsizei; 2],
+    size: IVector2<GLsizei>,
     /// frame
     frame: Frame,
     /// colors
@@ -80,7 +81,7 @@ impl Screen {
             None
         };
         Ok(Screen {
-            size: [width, height],
+            size: IVector2::new(width, height),
             frame,
             colors,
             depth,
@@ -88,9 +89,14 @@ impl Screen {
     }
     // ========================================================================
     /// size
-    pub fn size(&self) -> &[GLint; 2] {
+    pub fn size(&self) -> &IVector2<GLint> {
         &self.size
     }
+    // ------------------------------------------------------------------------
+    /// viewport
+    pub fn viewport(&self) -> IRect<GLint> {
+        IRect::new(0, 0, self.size[0], self.size[1])
+    }
     // ========================================================================
     /// as_color
     pub fn as_color(&self, i: usize) -> &Texture {
@@ -125,7 +131,8 @@ impl Bind for Screen {
         self.frame.bind()?;
         gl_result(|| -> StdResult<(), ()> {
             unsafe {
-                ::gl::Viewport(0, 0, self.size[0], self.size[1]);
+                let v = self.viewport();
+                ::gl::Viewport(v.x(), v.y(), v.width(), v.height());
             }
             Ok(())
         })?;
@@ -6,14 +6,14 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/03/08
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 use gl::types::*;
 // ----------------------------------------------------------------------------
 use sif_manager::ManagedValue;
-use sif_math::Float;
+use sif_math::{Float, IVector2};
 use sif_renderer::{Bind, Program, Texture};
 // ----------------------------------------------------------------------------
 use super::{
@@ -80,7 +80,7 @@ where
     }
     // ========================================================================
     /// size
-    pub fn size(&self) -> &[i32; 2] {
+    pub fn size(&self) -> &IVector2<GLint> {
         self.map.size()
     }
     // ========================================================================
@@ -6,7 +6,7 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/27
-//  @date 2019/07/09
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // const  =====================================================================
@@ -22,7 +22,7 @@ use std::{
 use gl::types::*;
 use uuid::Uuid;
 // ----------------------------------------------------------------------------
-use sif_math::Matrix4x4;
+use sif_math::{IRect, IVector2, Matrix4x4};
 use sif_renderer::Texture;
 use sif_three::new_mat4_tra;
 // ----------------------------------------------------------------------------
@@ -82,7 +82,7 @@ pub struct Font<'a, 'b> {
     /// added
     added: Vec<char>,
     /// cursor
-    cursor: (GLsizei, GLsizei),
+    cursor: IVector2<GLsizei>,
     /// width
     width: GLsizei,
     /// height
@@ -116,7 +116,7 @@ impl<'a, 'b> Font<'a, 'b> {
             glyphs: BTreeMap::new(),
             textures: vec![Self::new_texture(width, height)?],
             added: Vec::new(),
-            cursor: (PADDING, PADDING),
+            cursor: IVector2::new(PADDING, PADDING),
             width,
             height,
         })
@@ -215,14 +215,21 @@ impl<'a, 'b> Font<'a, 'b> {
             )?;
             let rect = surface.rect();
 
-            if self.width < (self.cursor.0 + rect.width() as GLsizei + PADDING)
-            {
-                self.cursor.0 = PADDING;
-                self.cursor.1 += ttf_height + PADDING;
-                if self.height
-                    < (self.cursor.1 + ttf_height as GLsizei + PADDING)
-                {
-                    self.cursor.1 = PADDING;
+            let atlas = IRect::new(0, 0, self.width, self.height);
+            let cell = IVector2::new(
+                rect.width() as GLsizei + PADDING,
+                ttf_height + PADDING,
+            );
+            if !atlas.contains(&IRect::from_min_max(
+                &self.cursor,
+                &(self.cursor + cell),
+            )) {
+                self.cursor = IVector2::new(PADDING, self.cursor[1] + cell[1]);
+                if !atlas.contains(&IRect::from_min_max(
+                    &self.cursor,
+                    &(self.cursor + cell),
+                )) {
+                    self.cursor[1] = PADDING;
                     self.textures
                         .push(Self::new_texture(self.width, self.height)?);
                 }
@@ -240,8 +247,8 @@ impl<'a, 'b> Font<'a, 'b> {
                     })?
                     .sub_image_2d(
                         0,
-                        self.cursor.0,
-                        self.cursor.1,
+                        self.cursor[0],
+                        self.cursor[1],
                         rect.width() as GLsizei,
                         rect.height() as GLsizei,
                         pxs.as_ptr() as *const ::std::os::raw::c_void,
@@ -261,15 +268,16 @@ impl<'a, 'b> Font<'a, 'b> {
                 glyph.texid = self.textures.len() - 1;
                 glyph.coords = [
                     [
-                        (self.cursor.0 as GLfloat) / self.width as GLfloat,
-                        ((self.cursor.1 as GLfloat
+                        (self.cursor[0] as GLfloat) / self.width as GLfloat,
+                        ((self.cursor[1] as GLfloat
                             + rect.height() as GLfloat)
                             / self.height as GLfloat),
                     ],
                     [
-                        ((self.cursor.0 as GLfloat + rect.width() as GLfloat)
+                        ((self.cursor[0] as GLfloat
+                            + rect.width() as GLfloat)
                             / self.width as GLfloat),
-                        (self.cursor.1 as GLfloat) / self.height as GLfloat,
+                        (self.cursor[1] as GLfloat) / self.height as GLfloat,
                     ],
                 ];
                 glyph.advance =
@@ -277,7 +285,7 @@ impl<'a, 'b> Font<'a, 'b> {
                 let _ = self.glyphs.insert(*c, glyph);
             }
 
-            self.cursor.0 += rect.width() as GLsizei + PADDING;
+            self.cursor[0] += rect.width() as GLsizei + PADDING;
         }
 
         Ok(self)
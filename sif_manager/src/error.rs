@@ -6,10 +6,11 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2018/05/12
-//  @date 2019/07/09
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
+#[cfg(feature = "std")]
 use std::error::Error as StdError;
 // ----------------------------------------------------------------------------
 use uuid::Uuid;
@@ -23,12 +24,13 @@ pub enum Error {
     Insert(Uuid),
 }
 // ============================================================================
-impl ::std::fmt::Display for Error {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        <Self as ::std::fmt::Debug>::fmt(self, f)
+impl ::core::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        <Self as ::core::fmt::Debug>::fmt(self, f)
     }
 }
 // ============================================================================
+#[cfg(feature = "std")]
 impl StdError for Error {
     // ========================================================================
     fn description(&self) -> &str {
@@ -46,7 +48,7 @@ impl StdError for Error {
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// type Result
-pub type Result<T> = ::std::result::Result<T, Error>;
+pub type Result<T> = ::core::result::Result<T, Error>;
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 #[cfg(test)]
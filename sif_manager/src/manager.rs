@@ -6,17 +6,19 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/02/27
-//  @date 2019/07/09
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
-use std::{
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    rc::{Rc, Weak},
+};
+use core::{
     borrow::Borrow,
     cell::RefCell,
-    collections::{btree_map, BTreeMap},
     fmt::Debug,
     hash::{Hash, Hasher},
-    rc::{Rc, Weak},
 };
 // ----------------------------------------------------------------------------
 use uuid::Uuid;
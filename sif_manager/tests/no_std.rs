@@ -0,0 +1,47 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! no_std.rs
+//!
+//! Uses the crate from a `#![no_std]` crate.
+//! `cargo test --no-default-features` builds `sif_manager` itself without
+//! std.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// attribute  =================================================================
+#![no_std]
+// use  =======================================================================
+use uuid::Uuid;
+// ----------------------------------------------------------------------------
+use sif_manager::{Error, Manager};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Item
+#[derive(Debug)]
+struct Item(Uuid, i32);
+// ============================================================================
+impl AsRef<Uuid> for Item {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[test]
+fn manager() {
+    let (a, b) = (Uuid::from_u128(1), Uuid::from_u128(2));
+    let mut m = Manager::default();
+    assert_eq!(m.insert(Item(a, 1)).unwrap(), a);
+    assert_eq!(m.insert(Item(b, 2)).unwrap(), b);
+    assert_eq!(m.insert(Item(a, 3)), Err(Error::Insert(a)));
+    assert_eq!(m.get(&b).unwrap().as_ref().borrow().1, 2);
+    let weak = m.get(&a).unwrap().downgrade();
+    let _ = m.remove(&a).unwrap();
+    assert!(weak.upgrade().is_none());
+    assert!(!m.contains_key(&a));
+}
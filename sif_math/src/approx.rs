@@ -0,0 +1,513 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! approx.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+use super::{
+    DualQuaternion, Float, Matrix2x2, Matrix2x3, Matrix3x2, Matrix3x3,
+    Matrix3x4, Matrix4x3, Matrix4x4, Quaternion, Vector2, Vector3, Vector4,
+};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait ApproxEq
+///
+/// Approximate equality, compared without `cleanup`.
+///
+/// # Examples
+///
+/// ```
+/// use ::sif_math::{ApproxEq, Vector3};
+///
+/// let a = Vector3::new(1.0f32, 2.0, 3.0);
+/// let b = Vector3::new(1.0f32, 2.0, 3.000_000_2);
+/// assert!(a.approx_eq(&b));
+/// assert!(!a.abs_diff_eq(&b, 0.0));
+/// assert!(a.ulps_eq(&b, 0.0, 4));
+/// ```
+pub trait ApproxEq {
+    /// type Scalar
+    type Scalar: Float;
+    // ========================================================================
+    /// default_epsilon
+    fn default_epsilon() -> Self::Scalar {
+        <Self::Scalar as ::num::Float>::epsilon()
+    }
+    // ------------------------------------------------------------------------
+    /// default_max_relative
+    fn default_max_relative() -> Self::Scalar {
+        <Self::Scalar as ::num::Float>::epsilon()
+    }
+    // ------------------------------------------------------------------------
+    /// default_max_ulps
+    fn default_max_ulps() -> u32 {
+        4
+    }
+    // ========================================================================
+    /// abs_diff_eq
+    ///
+    /// `|a - b| <= epsilon` for every component.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Scalar) -> bool;
+    // ------------------------------------------------------------------------
+    /// relative_eq
+    ///
+    /// `|a - b| <= epsilon`, or `|a - b| <= max(|a|, |b|) * max_relative`
+    /// for every component.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Scalar,
+        max_relative: Self::Scalar,
+    ) -> bool;
+    // ------------------------------------------------------------------------
+    /// ulps_eq
+    ///
+    /// `|a - b| <= epsilon`, or `a` and `b` have the same sign and are at
+    /// most `max_ulps` units in the last place apart, for every component.
+    fn ulps_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Scalar,
+        max_ulps: u32,
+    ) -> bool;
+    // ========================================================================
+    /// approx_eq
+    ///
+    /// `relative_eq` with the default tolerances.
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(
+            other,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// ulp
+///
+/// The spacing of floating point numbers at `x`.
+fn ulp<V: Float>(x: V) -> V {
+    if x.abs() < V::min_positive_value() {
+        // subnormal
+        return V::min_positive_value() * V::epsilon();
+    }
+    let (_, e, _) = x.integer_decode();
+    let two = V::one() + V::one();
+    let h = i32::from(e / 2);
+    // split the power so that neither half overflows
+    two.powi(h) * two.powi(i32::from(e) - h)
+}
+// ============================================================================
+impl<V> ApproxEq for V
+where
+    V: Float,
+{
+    type Scalar = V;
+    // ========================================================================
+    fn abs_diff_eq(&self, other: &Self, epsilon: V) -> bool {
+        (*self - *other).abs() <= epsilon
+    }
+    // ------------------------------------------------------------------------
+    fn relative_eq(&self, other: &Self, epsilon: V, max_relative: V) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+        let diff = (*self - *other).abs();
+        if diff <= epsilon {
+            return true;
+        }
+        diff <= self.abs().max(other.abs()) * max_relative
+    }
+    // ------------------------------------------------------------------------
+    fn ulps_eq(&self, other: &Self, epsilon: V, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_sign_positive() != other.is_sign_positive()
+            || self.is_infinite()
+            || other.is_infinite()
+        {
+            return false;
+        }
+        let diff = (*self - *other).abs();
+        match V::from(max_ulps) {
+            Some(n) => diff <= ulp(self.abs().max(other.abs())) * n,
+            None => false,
+        }
+    }
+}
+// ============================================================================
+/// approx_eq_define!
+macro_rules! approx_eq_define {
+    ($name:ident) => {
+        impl<V> ApproxEq for $name<V>
+        where
+            V: Float,
+        {
+            type Scalar = V;
+            // ================================================================
+            fn abs_diff_eq(&self, other: &Self, epsilon: V) -> bool {
+                self.iter()
+                    .zip(other.iter())
+                    .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+            // ----------------------------------------------------------------
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: V,
+                max_relative: V,
+            ) -> bool {
+                self.iter()
+                    .zip(other.iter())
+                    .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+            // ----------------------------------------------------------------
+            fn ulps_eq(
+                &self,
+                other: &Self,
+                epsilon: V,
+                max_ulps: u32,
+            ) -> bool {
+                self.iter()
+                    .zip(other.iter())
+                    .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+    };
+}
+// ============================================================================
+approx_eq_define!(Vector2);
+approx_eq_define!(Vector3);
+approx_eq_define!(Vector4);
+approx_eq_define!(Matrix2x2);
+approx_eq_define!(Matrix2x3);
+approx_eq_define!(Matrix3x2);
+approx_eq_define!(Matrix3x3);
+approx_eq_define!(Matrix3x4);
+approx_eq_define!(Matrix4x3);
+approx_eq_define!(Matrix4x4);
+// ============================================================================
+impl<V> ApproxEq for Quaternion<V>
+where
+    V: Float,
+{
+    type Scalar = V;
+    // ========================================================================
+    /// `q` and `-q` are the same rotation.
+    fn abs_diff_eq(&self, other: &Self, epsilon: V) -> bool {
+        self.as_vector4().abs_diff_eq(other.as_vector4(), epsilon)
+            || self
+                .as_vector4()
+                .abs_diff_eq((-*other).as_vector4(), epsilon)
+    }
+    // ------------------------------------------------------------------------
+    /// `q` and `-q` are the same rotation.
+    fn relative_eq(&self, other: &Self, epsilon: V, max_relative: V) -> bool {
+        let (a, b) = (self.as_vector4(), other.as_vector4());
+        a.relative_eq(b, epsilon, max_relative)
+            || a.relative_eq((-*other).as_vector4(), epsilon, max_relative)
+    }
+    // ------------------------------------------------------------------------
+    /// `q` and `-q` are the same rotation.
+    fn ulps_eq(&self, other: &Self, epsilon: V, max_ulps: u32) -> bool {
+        let (a, b) = (self.as_vector4(), other.as_vector4());
+        a.ulps_eq(b, epsilon, max_ulps)
+            || a.ulps_eq((-*other).as_vector4(), epsilon, max_ulps)
+    }
+}
+// ============================================================================
+impl<V> ApproxEq for DualQuaternion<V>
+where
+    V: Float,
+{
+    type Scalar = V;
+    // ========================================================================
+    /// `dq` and `-dq` are the same transform.
+    fn abs_diff_eq(&self, other: &Self, epsilon: V) -> bool {
+        let f = |o: &Self| {
+            self.real()
+                .as_vector4()
+                .abs_diff_eq(o.real().as_vector4(), epsilon)
+                && self
+                    .dual()
+                    .as_vector4()
+                    .abs_diff_eq(o.dual().as_vector4(), epsilon)
+        };
+        f(other) || f(&-*other)
+    }
+    // ------------------------------------------------------------------------
+    /// `dq` and `-dq` are the same transform.
+    fn relative_eq(&self, other: &Self, epsilon: V, max_relative: V) -> bool {
+        let f = |o: &Self| {
+            self.real().as_vector4().relative_eq(
+                o.real().as_vector4(),
+                epsilon,
+                max_relative,
+            ) && self.dual().as_vector4().relative_eq(
+                o.dual().as_vector4(),
+                epsilon,
+                max_relative,
+            )
+        };
+        f(other) || f(&-*other)
+    }
+    // ------------------------------------------------------------------------
+    /// `dq` and `-dq` are the same transform.
+    fn ulps_eq(&self, other: &Self, epsilon: V, max_ulps: u32) -> bool {
+        let f = |o: &Self| {
+            self.real().as_vector4().ulps_eq(
+                o.real().as_vector4(),
+                epsilon,
+                max_ulps,
+            ) && self.dual().as_vector4().ulps_eq(
+                o.dual().as_vector4(),
+                epsilon,
+                max_ulps,
+            )
+        };
+        f(other) || f(&-*other)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// approx_panic
+#[track_caller]
+fn approx_panic<T: ::core::fmt::Debug>(
+    name: &str,
+    a: &T,
+    b: &T,
+    s: &[&str; 2],
+) {
+    panic!(
+        "{}!({}, {}) failed\n  left: {:?}\n right: {:?}",
+        name, s[0], s[1], a, b
+    );
+}
+// ----------------------------------------------------------------------------
+/// assert_abs_diff_eq
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_abs_diff_eq<T>(
+    a: &T,
+    b: &T,
+    epsilon: Option<T::Scalar>,
+    s: &[&str; 2],
+) where
+    T: ApproxEq + ::core::fmt::Debug,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    if !a.abs_diff_eq(b, epsilon) {
+        approx_panic("assert_approx_eq", a, b, s);
+    }
+}
+// ----------------------------------------------------------------------------
+/// assert_relative_eq
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_relative_eq<T>(
+    a: &T,
+    b: &T,
+    epsilon: Option<T::Scalar>,
+    max_relative: Option<T::Scalar>,
+    s: &[&str; 2],
+) where
+    T: ApproxEq + ::core::fmt::Debug,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    let max_relative = max_relative.unwrap_or_else(T::default_max_relative);
+    if !a.relative_eq(b, epsilon, max_relative) {
+        approx_panic("assert_relative_eq", a, b, s);
+    }
+}
+// ----------------------------------------------------------------------------
+/// assert_ulps_eq
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_ulps_eq<T>(
+    a: &T,
+    b: &T,
+    epsilon: Option<T::Scalar>,
+    max_ulps: Option<u32>,
+    s: &[&str; 2],
+) where
+    T: ApproxEq + ::core::fmt::Debug,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    let max_ulps = max_ulps.unwrap_or_else(T::default_max_ulps);
+    if !a.ulps_eq(b, epsilon, max_ulps) {
+        approx_panic("assert_ulps_eq", a, b, s);
+    }
+}
+// ============================================================================
+/// assert_approx_eq!
+///
+/// `assert_approx_eq!(a, b)` or `assert_approx_eq!(a, b, epsilon = e)`
+/// asserts `ApproxEq::abs_diff_eq`.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::assert_abs_diff_eq(
+            &$a,
+            &$b,
+            None,
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+    ($a:expr, $b:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_abs_diff_eq(
+            &$a,
+            &$b,
+            Some($epsilon),
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+}
+// ----------------------------------------------------------------------------
+/// assert_relative_eq!
+///
+/// `assert_relative_eq!(a, b)`, optionally followed by `epsilon = e` and / or
+/// `max_relative = r`, asserts `ApproxEq::relative_eq`.
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::assert_relative_eq(
+            &$a,
+            &$b,
+            None,
+            None,
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+    ($a:expr, $b:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_relative_eq(
+            &$a,
+            &$b,
+            Some($epsilon),
+            None,
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+    ($a:expr, $b:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::assert_relative_eq(
+            &$a,
+            &$b,
+            None,
+            Some($max_relative),
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+    ($a:expr, $b:expr, epsilon = $epsilon:expr,
+     max_relative = $max_relative:expr $(,)?) => {
+        $crate::assert_relative_eq(
+            &$a,
+            &$b,
+            Some($epsilon),
+            Some($max_relative),
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+}
+// ----------------------------------------------------------------------------
+/// assert_ulps_eq!
+///
+/// `assert_ulps_eq!(a, b)`, optionally followed by `epsilon = e` and / or
+/// `max_ulps = n`, asserts `ApproxEq::ulps_eq`.
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::assert_ulps_eq(
+            &$a,
+            &$b,
+            None,
+            None,
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+    ($a:expr, $b:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_ulps_eq(
+            &$a,
+            &$b,
+            Some($epsilon),
+            None,
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+    ($a:expr, $b:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_ulps_eq(
+            &$a,
+            &$b,
+            None,
+            Some($max_ulps),
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+    ($a:expr, $b:expr, epsilon = $epsilon:expr,
+     max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_ulps_eq(
+            &$a,
+            &$b,
+            Some($epsilon),
+            Some($max_ulps),
+            &[stringify!($a), stringify!($b)],
+        )
+    };
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    // ========================================================================
+    #[test]
+    fn scalar() {
+        assert!(1.0f32.abs_diff_eq(&1.1, 0.2));
+        assert!(!1.0f32.abs_diff_eq(&1.1, 0.05));
+        assert!(1000.0f64.relative_eq(&1001.0, 0.0, 1.0e-3));
+        assert!(!1000.0f64.relative_eq(&1002.0, 0.0, 1.0e-3));
+        let x = 1.0f32;
+        let y = f32::from_bits(x.to_bits() + 3);
+        assert!(x.ulps_eq(&y, 0.0, 3));
+        assert!(!x.ulps_eq(&y, 0.0, 2));
+        assert!(!x.ulps_eq(&-x, 0.0, u32::MAX));
+        let t = f64::from_bits(1);
+        assert!(t.ulps_eq(&f64::from_bits(5), 0.0, 4));
+        assert!(!f32::NAN.approx_eq(&f32::NAN));
+    }
+    // ========================================================================
+    #[test]
+    fn quaternion() {
+        let q = Quaternion::new(0.0f32, 0.6, 0.0, 0.8);
+        assert!(q.approx_eq(&-q));
+        assert!(!q.approx_eq(&q.conjugate()));
+        assert_approx_eq!(q, -q);
+        assert_relative_eq!(q, -q, max_relative = 0.0);
+        assert_ulps_eq!(q, -q, max_ulps = 0);
+    }
+    // ========================================================================
+    #[test]
+    fn matrix() {
+        let m = Matrix4x4::<f64>::default();
+        let mut n = m;
+        n[3][0] = 1.0e-9;
+        assert_approx_eq!(m, n, epsilon = 1.0e-8);
+        assert!(!m.approx_eq(&n));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    #[should_panic]
+    fn assert_fail() {
+        assert_approx_eq!(Vector2::new(0.0f32, 1.0), Vector2::new(0.0, 1.1));
+    }
+}
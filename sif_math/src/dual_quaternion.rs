@@ -0,0 +1,367 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! dual_quaternion.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+use core::ops::{Add, Mul, MulAssign, Neg};
+// ----------------------------------------------------------------------------
+use super::{Float, Matrix3x3, Matrix4x4, Quaternion, Vector3, Vector4};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct DualQuaternion
+///
+/// `real + ε dual`. A unit dual quaternion is a rigid transform, whose
+/// `real` is the rotation and whose `dual` is `translation * real / 2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DualQuaternion<V: Float> {
+    /// real
+    real: Quaternion<V>,
+    /// dual
+    dual: Quaternion<V>,
+}
+// ============================================================================
+impl<V> Default for DualQuaternion<V>
+where
+    V: Float,
+{
+    fn default() -> Self {
+        DualQuaternion {
+            real: Quaternion::default(),
+            dual: Quaternion::new(V::zero(), V::zero(), V::zero(), V::zero()),
+        }
+    }
+}
+// ============================================================================
+impl<V> From<Matrix4x4<V>> for DualQuaternion<V>
+where
+    V: Float,
+{
+    /// The matrix must be a rotation followed by a translation.
+    fn from(m: Matrix4x4<V>) -> Self {
+        DualQuaternion::from_rotation_translation(
+            &Quaternion::from(m),
+            &Vector3::from(m[3]),
+        )
+    }
+}
+// ============================================================================
+impl<V> From<DualQuaternion<V>> for Matrix4x4<V>
+where
+    V: Float,
+{
+    /// The dual quaternion must be a unit one.
+    fn from(dq: DualQuaternion<V>) -> Self {
+        let r = Matrix3x3::from(dq.real);
+        let t = dq.translation();
+        Matrix4x4::from([
+            Vector4::from_vector3(&r[0], V::zero()),
+            Vector4::from_vector3(&r[1], V::zero()),
+            Vector4::from_vector3(&r[2], V::zero()),
+            Vector4::from_vector3(&t, V::one()),
+        ])
+    }
+}
+// ----------------------------------------------------------------------------
+impl From<DualQuaternion<f32>> for DualQuaternion<f64> {
+    fn from(src: DualQuaternion<f32>) -> Self {
+        DualQuaternion::new(src.real.into(), src.dual.into())
+    }
+}
+// ============================================================================
+impl<V> Neg for DualQuaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        DualQuaternion::new(-self.real, -self.dual)
+    }
+}
+// ============================================================================
+impl<V> Add for DualQuaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        DualQuaternion::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+// ============================================================================
+impl<V> Mul<V> for DualQuaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    fn mul(self, rhs: V) -> Self::Output {
+        DualQuaternion::new(self.real * rhs, self.dual * rhs)
+    }
+}
+// ============================================================================
+impl<V> Mul for DualQuaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    /// `a * b` transforms by `b` first, then by `a`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        DualQuaternion::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real,
+        )
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> MulAssign for DualQuaternion<V>
+where
+    V: Float,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+// ============================================================================
+impl<V> DualQuaternion<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    pub fn new(real: Quaternion<V>, dual: Quaternion<V>) -> Self {
+        DualQuaternion { real, dual }
+    }
+    // ------------------------------------------------------------------------
+    /// from_rotation_translation
+    ///
+    /// Rotates by `rotate` first, then translates by `translate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::sif_math::{DualQuaternion, Quaternion, Vector3};
+    ///
+    /// let dq = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::from_axis_angle(
+    ///         &Vector3::new(0.0f32, 0.0, 1.0),
+    ///         ::core::f32::consts::FRAC_PI_2,
+    ///     )
+    ///     .unwrap(),
+    ///     &Vector3::new(1.0, 2.0, 3.0),
+    /// );
+    /// let v = dq.transform_point(&Vector3::new(1.0, 0.0, 0.0));
+    /// assert!((v - Vector3::new(1.0, 3.0, 3.0)).length() < 1.0e-6);
+    /// ```
+    pub fn from_rotation_translation(
+        rotate: &Quaternion<V>,
+        translate: &Vector3<V>,
+    ) -> Self {
+        let half = V::from(0.5).unwrap();
+        DualQuaternion::new(
+            *rotate,
+            Quaternion::from_vector3(translate, V::zero()) * *rotate * half,
+        )
+    }
+    // ========================================================================
+    /// real
+    pub fn real(&self) -> &Quaternion<V> {
+        &self.real
+    }
+    // ------------------------------------------------------------------------
+    /// dual
+    pub fn dual(&self) -> &Quaternion<V> {
+        &self.dual
+    }
+    // ------------------------------------------------------------------------
+    /// cast
+    ///
+    /// `None` if a component is out of the range of `U`.
+    pub fn cast<U: Float>(&self) -> Option<DualQuaternion<U>> {
+        Some(DualQuaternion::new(self.real.cast()?, self.dual.cast()?))
+    }
+    // ------------------------------------------------------------------------
+    /// rotation
+    pub fn rotation(&self) -> Quaternion<V> {
+        self.real
+    }
+    // ------------------------------------------------------------------------
+    /// translation
+    pub fn translation(&self) -> Vector3<V> {
+        (self.dual * self.real.conjugate()).vector() * V::from(2).unwrap()
+    }
+    // ========================================================================
+    /// conjugate
+    ///
+    /// The quaternion conjugate of both parts. This is the inverse of a unit
+    /// dual quaternion.
+    pub fn conjugate(&self) -> Self {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+    // ------------------------------------------------------------------------
+    /// dot
+    ///
+    /// The dot product of the real parts.
+    pub fn dot(&self, rhs: &Self) -> V {
+        self.real.dot(&rhs.real)
+    }
+    // ------------------------------------------------------------------------
+    /// normalize
+    ///
+    /// Makes `real` a unit quaternion and `dual` orthogonal to it.
+    /// A zero real part becomes the identity.
+    pub fn normalize(&mut self) -> &mut Self {
+        let l = self.real.length();
+        if l < V::epsilon().sqrt() {
+            *self = DualQuaternion::default();
+            return self;
+        }
+        let real = self.real * (V::one() / l);
+        let dual = self.dual * (V::one() / l);
+        self.dual = dual - real * real.dot(&dual);
+        self.real = real;
+        self
+    }
+    // ========================================================================
+    /// transform_point
+    pub fn transform_point(&self, v: &Vector3<V>) -> Vector3<V> {
+        self.real.rotate(v) + self.translation()
+    }
+    // ------------------------------------------------------------------------
+    /// transform_direction
+    pub fn transform_direction(&self, v: &Vector3<V>) -> Vector3<V> {
+        self.real.rotate(v)
+    }
+    // ========================================================================
+    /// new_blend
+    ///
+    /// Dual quaternion linear blending. Each dual quaternion is flipped
+    /// into the hemisphere of the first one before the weighted sum is
+    /// normalized. An empty slice yields the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::sif_math::{DualQuaternion, Quaternion, Vector3};
+    ///
+    /// let a = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::default(),
+    ///     &Vector3::new(0.0f32, 0.0, 0.0),
+    /// );
+    /// let b = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::default(),
+    ///     &Vector3::new(2.0f32, 0.0, 0.0),
+    /// );
+    /// let c = DualQuaternion::new_blend(&[(a, 0.5), (b, 0.5)]);
+    /// assert!((c.translation() - Vector3::new(1.0, 0.0, 0.0)).length() < 1.0e-6);
+    /// ```
+    pub fn new_blend(src: &[(Self, V)]) -> Self {
+        let mut iter = src.iter();
+        let (pivot, w) = match iter.next() {
+            Some(x) => *x,
+            None => return DualQuaternion::default(),
+        };
+        let mut acc = pivot * w;
+        for &(dq, w) in iter {
+            if pivot.dot(&dq) < V::zero() {
+                acc = acc + dq * -w;
+            } else {
+                acc = acc + dq * w;
+            }
+        }
+        *acc.normalize()
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::assert_approx_eq;
+    // ========================================================================
+    fn dq(axis: [f32; 3], angle: f32, t: [f32; 3]) -> DualQuaternion<f32> {
+        DualQuaternion::from_rotation_translation(
+            &Quaternion::from_axis_angle(&Vector3::from(axis), angle).unwrap(),
+            &Vector3::from(t),
+        )
+    }
+    // ========================================================================
+    #[test]
+    fn cast() {
+        let a = dq([1.0, 2.0, 3.0], 0.7, [1.0, -2.0, 0.5]);
+        let b = DualQuaternion::<f64>::from(a);
+        assert_eq!(b.cast::<f32>(), Some(a));
+        assert_eq!(a.cast::<f64>(), Some(b));
+        let big = DualQuaternion::new(
+            *b.real(),
+            Quaternion::new(1.0e300, 0.0, 0.0, 0.0),
+        );
+        assert!(big.cast::<f32>().is_none());
+    }
+    // ========================================================================
+    #[test]
+    fn matrix() {
+        let a = dq([1.0, 2.0, 3.0], 0.7, [1.0, -2.0, 0.5]);
+        let m = Matrix4x4::from(a);
+        let v = Vector3::new(0.3f32, -1.0, 2.0);
+        let p = m * Vector4::from_vector3(&v, 1.0);
+        assert!((Vector3::from(p) - a.transform_point(&v)).length() < 1.0e-5);
+        let b = DualQuaternion::from(m);
+        assert!(
+            (b.translation() - Vector3::new(1.0, -2.0, 0.5)).length() < 1.0e-5
+        );
+        assert!((1.0 - a.dot(&b).abs()) < 1.0e-6);
+    }
+    // ========================================================================
+    #[test]
+    fn mul() {
+        let a = dq([1.0, 2.0, 3.0], 0.7, [1.0, -2.0, 0.5]);
+        let b = dq([-2.0, 0.5, 1.0], -1.3, [0.0, 3.0, -1.0]);
+        assert_approx_eq!(
+            Matrix4x4::from(a * b),
+            Matrix4x4::from(a) * Matrix4x4::from(b),
+            epsilon = 1.0e-5
+        );
+        assert_approx_eq!(
+            Matrix4x4::from(a * a.conjugate()),
+            Matrix4x4::default(),
+            epsilon = 1.0e-5
+        );
+    }
+    // ========================================================================
+    #[test]
+    fn normalize() {
+        let a = dq([0.0, 1.0, 0.0], 1.1, [4.0, 5.0, 6.0]);
+        let mut b = DualQuaternion::new(
+            a.real * 3.0 + Quaternion::new(0.0, 0.1, 0.0, 0.0),
+            a.dual * 3.0,
+        );
+        let _ = b.normalize();
+        assert!((b.real.length() - 1.0).abs() < 1.0e-6);
+        assert!(b.real.dot(&b.dual).abs() < 1.0e-6);
+    }
+    // ========================================================================
+    #[test]
+    fn blend() {
+        let a = dq([0.0, 0.0, 1.0], 0.0, [1.0, 0.0, 0.0]);
+        let b = dq([0.0, 0.0, 1.0], 1.0, [1.0, 0.0, 0.0]);
+        // -b is the same transform as b
+        let c = DualQuaternion::new_blend(&[(a, 0.5), (-b, 0.5)]);
+        let d = dq([0.0, 0.0, 1.0], 0.5, [1.0, 0.0, 0.0]);
+        assert_approx_eq!(
+            Matrix4x4::from(c),
+            Matrix4x4::from(d),
+            epsilon = 1.0e-5
+        );
+        assert_eq!(
+            DualQuaternion::<f32>::new_blend(&[]),
+            DualQuaternion::default()
+        );
+    }
+}
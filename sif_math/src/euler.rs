@@ -0,0 +1,279 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! euler.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use super::{Float, Matrix3x3, Quaternion, Vector3};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// enum EulerOrder
+///
+/// The axes in the order they are applied, about the fixed (extrinsic)
+/// axes. `XYZ` rotates about X first, then Y, then Z, so the matrix is
+/// `Rz * Ry * Rx`. The same angles read right to left give the intrinsic
+/// order.
+///
+/// Euler angles are passed as a `Vector3` holding the angle of the first,
+/// the second and the third rotation, in that order.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// XYZ
+    XYZ,
+    /// XZY
+    XZY,
+    /// YXZ
+    YXZ,
+    /// YZX
+    YZX,
+    /// ZXY
+    ZXY,
+    /// ZYX
+    ZYX,
+    /// XYX
+    XYX,
+    /// XZX
+    XZX,
+    /// YXY
+    YXY,
+    /// YZY
+    YZY,
+    /// ZXZ
+    ZXZ,
+    /// ZYZ
+    ZYZ,
+}
+// ============================================================================
+impl EulerOrder {
+    // ========================================================================
+    /// axes
+    ///
+    /// The axis indices in the order they are applied.
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// is_proper
+    ///
+    /// Whether the first and the last axis are the same.
+    pub fn is_proper(self) -> bool {
+        let a = self.axes();
+        a[0] == a[2]
+    }
+    // ------------------------------------------------------------------------
+    /// is_odd
+    ///
+    /// Whether the first two axes are an odd permutation.
+    fn is_odd(self) -> bool {
+        let a = self.axes();
+        (a[0] + 1) % 3 != a[1]
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// quaternion_axis
+fn quaternion_axis<V: Float>(axis: usize, angle: V) -> Quaternion<V> {
+    let (sin, cos) = (angle / V::from(2).unwrap()).sin_cos();
+    let mut q = Quaternion::new(V::zero(), V::zero(), V::zero(), cos);
+    q[axis] = sin;
+    q
+}
+// ============================================================================
+impl<V> Quaternion<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// from_euler
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::sif_math::{EulerOrder, Quaternion, Vector3};
+    ///
+    /// let q = Quaternion::from_euler(
+    ///     &Vector3::new(0.0f32, 0.0, 0.5),
+    ///     EulerOrder::XYZ,
+    /// );
+    /// let r = Quaternion::from_axis_angle(
+    ///     &Vector3::new(0.0f32, 0.0, 1.0),
+    ///     0.5,
+    /// )
+    /// .unwrap();
+    /// assert!((q - r).length() < 1.0e-6);
+    /// ```
+    pub fn from_euler(angles: &Vector3<V>, order: EulerOrder) -> Self {
+        let a = order.axes();
+        quaternion_axis(a[2], angles[2])
+            * quaternion_axis(a[1], angles[1])
+            * quaternion_axis(a[0], angles[0])
+    }
+    // ------------------------------------------------------------------------
+    /// to_euler
+    pub fn to_euler(&self, order: EulerOrder) -> Vector3<V> {
+        Matrix3x3::from(*self.clone().normalize()).to_euler(order)
+    }
+}
+// ============================================================================
+impl<V> Matrix3x3<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// from_euler
+    pub fn from_euler(angles: &Vector3<V>, order: EulerOrder) -> Self {
+        Matrix3x3::from(Quaternion::from_euler(angles, order))
+    }
+    // ------------------------------------------------------------------------
+    /// to_euler
+    ///
+    /// The matrix must be a pure rotation. The second angle is in
+    /// `[-PI / 2, PI / 2]` for Tait-Bryan orders and in `[0, PI]` for proper
+    /// orders. In gimbal lock the third angle is zero and the whole remaining
+    /// rotation goes to the first angle.
+    pub fn to_euler(&self, order: EulerOrder) -> Vector3<V> {
+        let a = order.axes();
+        let i = a[0];
+        let (j, k) = if order.is_odd() {
+            ((i + 2) % 3, (i + 1) % 3)
+        } else {
+            ((i + 1) % 3, (i + 2) % 3)
+        };
+        // r(row, col)
+        let r = |row: usize, col: usize| self[col][row];
+        let limit = V::epsilon() * V::from(16).unwrap();
+        let (mut x, mut y, mut z);
+        let lock;
+        if order.is_proper() {
+            let sy = r(i, j).hypot(r(i, k));
+            y = sy.atan2(r(i, i));
+            lock = sy <= limit;
+            if !lock {
+                x = r(i, j).atan2(r(i, k));
+                z = r(j, i).atan2(-r(k, i));
+            } else {
+                x = (-r(j, k)).atan2(r(j, j));
+                z = V::zero();
+            }
+        } else {
+            let cy = r(i, i).hypot(r(j, i));
+            y = (-r(k, i)).atan2(cy);
+            lock = cy <= limit;
+            if !lock {
+                x = r(k, j).atan2(r(k, k));
+                z = r(j, i).atan2(r(i, i));
+            } else {
+                x = (-r(j, k)).atan2(r(j, j));
+                z = V::zero();
+            }
+        }
+        if order.is_odd() {
+            x = -x;
+            y = -y;
+            z = -z;
+        }
+        if order.is_proper() && lock {
+            // a half turn either way
+            y = y.abs();
+        } else if order.is_proper() && y < V::zero() {
+            let pi = V::from(::core::f64::consts::PI).unwrap();
+            let wrap = |v: V| if V::zero() < v { v - pi } else { v + pi };
+            x = wrap(x);
+            y = -y;
+            z = wrap(z);
+        }
+        Vector3::new(x, y, z)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    // ========================================================================
+    const ORDERS: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+    // ========================================================================
+    #[test]
+    fn matrix_order() {
+        let e = Vector3::new(0.3f64, -0.7, 1.1);
+        let m = Matrix3x3::from_euler(&e, EulerOrder::XYZ);
+        let (x, y, z) = (
+            Matrix3x3::from(quaternion_axis(0, e[0])),
+            Matrix3x3::from(quaternion_axis(1, e[1])),
+            Matrix3x3::from(quaternion_axis(2, e[2])),
+        );
+        let r = z * y * x;
+        for c in 0..3 {
+            assert!((m[c] - r[c]).length() < 1.0e-12);
+        }
+    }
+    // ========================================================================
+    #[test]
+    fn round_trip() {
+        for order in ORDERS.iter() {
+            let e = if order.is_proper() {
+                Vector3::new(0.3f64, 1.2, -0.9)
+            } else {
+                Vector3::new(0.3f64, -0.7, 1.1)
+            };
+            let q = Quaternion::from_euler(&e, *order);
+            let f = q.to_euler(*order);
+            assert!((e - f).length() < 1.0e-9, "{:?} {:?}", order, f);
+        }
+    }
+    // ========================================================================
+    #[test]
+    fn gimbal_lock() {
+        let half_pi = ::core::f64::consts::FRAC_PI_2;
+        let pi = ::core::f64::consts::PI;
+        for order in ORDERS.iter() {
+            let ys = if order.is_proper() {
+                [0.0, pi]
+            } else {
+                [half_pi, -half_pi]
+            };
+            for &y in &ys {
+                let e = Vector3::new(0.4f64, y, 0.2);
+                let q = Quaternion::from_euler(&e, *order);
+                let f = q.to_euler(*order);
+                let p = Quaternion::from_euler(&f, *order);
+                assert!((1.0 - q.dot(&p).abs()) < 1.0e-9, "{:?} {}", order, y);
+                assert!((f[1] - y).abs() < 1.0e-6, "{:?} {:?}", order, f);
+                assert_eq!(f[2], 0.0, "{:?} {:?}", order, f);
+            }
+        }
+    }
+}
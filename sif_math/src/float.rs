@@ -6,23 +6,23 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/08
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// trait Float
 pub trait Float:
-    ::std::fmt::Debug
-    + ::std::default::Default
-    + ::std::ops::Neg
-    + ::std::ops::Add
-    + ::std::ops::AddAssign
-    + ::std::ops::Sub
-    + ::std::ops::SubAssign
-    + ::std::ops::Mul
-    + ::std::ops::MulAssign
-    + ::std::ops::Div
-    + ::std::ops::DivAssign
+    ::core::fmt::Debug
+    + ::core::default::Default
+    + ::core::ops::Neg
+    + ::core::ops::Add
+    + ::core::ops::AddAssign
+    + ::core::ops::Sub
+    + ::core::ops::SubAssign
+    + ::core::ops::Mul
+    + ::core::ops::MulAssign
+    + ::core::ops::Div
+    + ::core::ops::DivAssign
     + ::num::cast::FromPrimitive
     + ::num::cast::NumCast
     + ::num::cast::ToPrimitive
@@ -33,17 +33,17 @@ pub trait Float:
 }
 // ============================================================================
 impl<T> Float for T where
-    T: ::std::fmt::Debug
-        + ::std::default::Default
-        + ::std::ops::Neg
-        + ::std::ops::Add
-        + ::std::ops::AddAssign
-        + ::std::ops::Sub
-        + ::std::ops::SubAssign
-        + ::std::ops::Mul
-        + ::std::ops::MulAssign
-        + ::std::ops::Div
-        + ::std::ops::DivAssign
+    T: ::core::fmt::Debug
+        + ::core::default::Default
+        + ::core::ops::Neg
+        + ::core::ops::Add
+        + ::core::ops::AddAssign
+        + ::core::ops::Sub
+        + ::core::ops::SubAssign
+        + ::core::ops::Mul
+        + ::core::ops::MulAssign
+        + ::core::ops::Div
+        + ::core::ops::DivAssign
         + ::num::cast::ToPrimitive
         + ::num::cast::FromPrimitive
         + ::num::cast::NumCast
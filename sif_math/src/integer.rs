@@ -6,23 +6,24 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/08
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// trait Integer
 pub trait Integer:
-    ::std::fmt::Debug
-    + ::std::default::Default
-    + ::std::ops::Neg
-    + ::std::ops::Add
-    + ::std::ops::AddAssign
-    + ::std::ops::Sub
-    + ::std::ops::SubAssign
-    + ::std::ops::Mul
-    + ::std::ops::MulAssign
-    + ::std::ops::Div
-    + ::std::ops::DivAssign
+    ::core::fmt::Debug
+    + ::core::marker::Copy
+    + ::core::default::Default
+    + ::core::ops::Neg
+    + ::core::ops::Add
+    + ::core::ops::AddAssign
+    + ::core::ops::Sub
+    + ::core::ops::SubAssign
+    + ::core::ops::Mul
+    + ::core::ops::MulAssign
+    + ::core::ops::Div
+    + ::core::ops::DivAssign
     + ::num::cast::FromPrimitive
     + ::num::cast::NumCast
     + ::num::cast::ToPrimitive
@@ -33,17 +34,18 @@ pub trait Integer:
 }
 // ============================================================================
 impl<T> Integer for T where
-    T: ::std::fmt::Debug
-        + ::std::default::Default
-        + ::std::ops::Neg
-        + ::std::ops::Add
-        + ::std::ops::AddAssign
-        + ::std::ops::Sub
-        + ::std::ops::SubAssign
-        + ::std::ops::Mul
-        + ::std::ops::MulAssign
-        + ::std::ops::Div
-        + ::std::ops::DivAssign
+    T: ::core::fmt::Debug
+        + ::core::marker::Copy
+        + ::core::default::Default
+        + ::core::ops::Neg
+        + ::core::ops::Add
+        + ::core::ops::AddAssign
+        + ::core::ops::Sub
+        + ::core::ops::SubAssign
+        + ::core::ops::Mul
+        + ::core::ops::MulAssign
+        + ::core::ops::Div
+        + ::core::ops::DivAssign
         + ::num::cast::ToPrimitive
         + ::num::cast::FromPrimitive
         + ::num::cast::NumCast
@@ -6,11 +6,13 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2018/08/05
-//  @date 2020/03/18
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
-use super::Float;
+use core::ops::{Add, Mul, Sub};
+// ----------------------------------------------------------------------------
+use super::{Float, Quaternion};
 // ============================================================================
 /// bezier
 pub fn bezier<V: Float>(v0: V, v1: V, v2: V, v3: V, t: V) -> V {
@@ -18,3 +20,146 @@ pub fn bezier<V: Float>(v0: V, v1: V, v2: V, v3: V, t: V) -> V {
     (it * it * ((it * v0) + (V::from(3).unwrap() * t * v1)))
         + (t * t * ((V::from(3).unwrap() * it * v2) + (t * v3)))
 }
+// ============================================================================
+/// lerp
+///
+/// # Examples
+///
+/// ```
+/// use ::sif_math::{lerp, Vector3};
+///
+/// assert_eq!(lerp(Vector3::new(0.0f32, 2.0, 4.0),
+///                 Vector3::new(2.0f32, 4.0, 8.0),
+///                 0.5f32),
+///            Vector3::new(1.0f32, 3.0, 6.0));
+/// ```
+pub fn lerp<T, V>(v0: T, v1: T, t: V) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<V, Output = T>,
+    V: Float,
+{
+    v0 + (v1 - v0) * t
+}
+// ============================================================================
+/// nlerp
+///
+/// Normalized linear interpolation along the shortest path.
+pub fn nlerp<V: Float>(
+    q0: &Quaternion<V>,
+    q1: &Quaternion<V>,
+    t: V,
+) -> Quaternion<V> {
+    let q1 = if q0.dot(q1) < V::zero() { -*q1 } else { *q1 };
+    *lerp(*q0, q1, t).normalize()
+}
+// ============================================================================
+/// slerp
+///
+/// Spherical linear interpolation along the shortest path.
+///
+/// # Examples
+///
+/// ```
+/// use ::sif_math::{slerp, Quaternion, Vector3};
+///
+/// let axis = Vector3::new(0.0f32, 1.0, 0.0);
+/// let q0 = Quaternion::from_axis_angle(&axis, 0.0).unwrap();
+/// let q1 = Quaternion::from_axis_angle(&axis, 1.0).unwrap();
+/// let q = slerp(&q0, &q1, 0.25);
+/// assert!((q.to_axis_angle().1 - 0.25).abs() < 1.0e-6);
+/// ```
+pub fn slerp<V: Float>(
+    q0: &Quaternion<V>,
+    q1: &Quaternion<V>,
+    t: V,
+) -> Quaternion<V> {
+    if q0.dot(q1) < V::zero() {
+        slerp_no_invert(q0, &-*q1, t)
+    } else {
+        slerp_no_invert(q0, q1, t)
+    }
+}
+// ----------------------------------------------------------------------------
+/// slerp_no_invert
+fn slerp_no_invert<V: Float>(
+    q0: &Quaternion<V>,
+    q1: &Quaternion<V>,
+    t: V,
+) -> Quaternion<V> {
+    let d = q0.dot(q1).max(-V::one()).min(V::one());
+    if V::one() - d.abs() < V::epsilon().sqrt() {
+        return *lerp(*q0, *q1, t).normalize();
+    }
+    let theta = d.acos();
+    let s = theta.sin();
+    *q0 * (((V::one() - t) * theta).sin() / s) + *q1 * ((t * theta).sin() / s)
+}
+// ============================================================================
+/// squad
+///
+/// Spherical cubic interpolation between `q1` and `q2`.
+/// `s1` and `s2` are the inner control points given by `squad_control`.
+pub fn squad<V: Float>(
+    q1: &Quaternion<V>,
+    q2: &Quaternion<V>,
+    s1: &Quaternion<V>,
+    s2: &Quaternion<V>,
+    t: V,
+) -> Quaternion<V> {
+    slerp_no_invert(
+        &slerp_no_invert(q1, q2, t),
+        &slerp_no_invert(s1, s2, t),
+        V::from(2).unwrap() * t * (V::one() - t),
+    )
+}
+// ----------------------------------------------------------------------------
+/// squad_control
+///
+/// The inner control point at `q1` for the key sequence `q0`, `q1`, `q2`.
+/// The keys should be in the same hemisphere.
+pub fn squad_control<V: Float>(
+    q0: &Quaternion<V>,
+    q1: &Quaternion<V>,
+    q2: &Quaternion<V>,
+) -> Quaternion<V> {
+    let inv = q1.conjugate();
+    let l = ((inv * *q0).ln() + (inv * *q2).ln())
+        * (-V::one() / V::from(4).unwrap());
+    *(*q1 * l.exp()).normalize()
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::Vector3;
+    use alloc::vec::Vec;
+    // ========================================================================
+    #[test]
+    fn slerp_shortest() {
+        let axis = Vector3::new(0.0f32, 0.0, 1.0);
+        let q0 = Quaternion::from_axis_angle(&axis, 0.1).unwrap();
+        let q1 = -Quaternion::from_axis_angle(&axis, 0.5).unwrap();
+        let q = slerp(&q0, &q1, 0.5);
+        let r = Quaternion::from_axis_angle(&axis, 0.3).unwrap();
+        assert!((1.0 - q.dot(&r).abs()) < 1.0e-6);
+        let n = nlerp(&q0, &q1, 0.5);
+        assert!((1.0 - n.dot(&r).abs()) < 1.0e-6);
+    }
+    // ========================================================================
+    #[test]
+    fn squad_ends() {
+        let axis = Vector3::new(1.0f32, 1.0, 0.0);
+        let q: Vec<_> = [0.0f32, 0.4, 1.1, 1.5]
+            .iter()
+            .map(|a| Quaternion::from_axis_angle(&axis, *a).unwrap())
+            .collect();
+        let s1 = squad_control(&q[0], &q[1], &q[2]);
+        let s2 = squad_control(&q[1], &q[2], &q[3]);
+        assert!((squad(&q[1], &q[2], &s1, &s2, 0.0) - q[1]).length() < 1e-6);
+        assert!((squad(&q[1], &q[2], &s1, &s2, 1.0) - q[2]).length() < 1e-6);
+        let m = squad(&q[1], &q[2], &s1, &s2, 0.5);
+        assert!((m.length() - 1.0).abs() < 1.0e-6);
+    }
+}
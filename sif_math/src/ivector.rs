@@ -0,0 +1,318 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! ivector.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub,
+    SubAssign,
+};
+// ----------------------------------------------------------------------------
+use super::Integer;
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// ivector_widen!
+///
+/// The lossless `From` between the components.
+macro_rules! ivector_widen {
+    ($name:ident, $n:expr, $from:ty, $to:ty) => {
+        impl From<$name<$from>> for $name<$to> {
+            fn from(src: $name<$from>) -> Self {
+                let mut inner = [0; $n];
+                for (d, s) in inner.iter_mut().zip(src.0.iter()) {
+                    *d = <$to>::from(*s);
+                }
+                $name(inner)
+            }
+        }
+    };
+}
+// ============================================================================
+/// ivector_ops!
+macro_rules! ivector_ops {
+    ($name:ident, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        // ====================================================================
+        impl<I> $op<I> for $name<I>
+        where
+            I: Integer,
+        {
+            type Output = Self;
+            fn $fn(mut self, rhs: I) -> Self::Output {
+                self.$fn_assign(rhs);
+                self
+            }
+        }
+        // --------------------------------------------------------------------
+        impl<I> $op_assign<I> for $name<I>
+        where
+            I: Integer,
+        {
+            fn $fn_assign(&mut self, rhs: I) {
+                for v in &mut self.0 {
+                    v.$fn_assign(rhs);
+                }
+            }
+        }
+        // ====================================================================
+        impl<I> $op for $name<I>
+        where
+            I: Integer,
+        {
+            type Output = Self;
+            fn $fn(mut self, rhs: Self) -> Self::Output {
+                self.$fn_assign(rhs);
+                self
+            }
+        }
+        // --------------------------------------------------------------------
+        impl<I> $op_assign for $name<I>
+        where
+            I: Integer,
+        {
+            fn $fn_assign(&mut self, rhs: Self) {
+                for (v, r) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    v.$fn_assign(*r);
+                }
+            }
+        }
+    };
+}
+// ============================================================================
+/// ivector_define!
+macro_rules! ivector_define {
+    ($name:ident, $n:expr) => {
+        // ////////////////////////////////////////////////////////////////////
+        // ====================================================================
+        /// struct $name
+        #[derive(
+            Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+        )]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
+        pub struct $name<I: Integer>([I; $n]);
+        // ====================================================================
+        impl<I> From<[I; $n]> for $name<I>
+        where
+            I: Integer,
+        {
+            fn from(src: [I; $n]) -> Self {
+                $name(src)
+            }
+        }
+        // --------------------------------------------------------------------
+        impl<I> From<$name<I>> for [I; $n]
+        where
+            I: Integer,
+        {
+            fn from(src: $name<I>) -> Self {
+                src.0
+            }
+        }
+        // ====================================================================
+        impl<I> Index<usize> for $name<I>
+        where
+            I: Integer,
+        {
+            type Output = I;
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+        // --------------------------------------------------------------------
+        impl<I> IndexMut<usize> for $name<I>
+        where
+            I: Integer,
+        {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.0[index]
+            }
+        }
+        // ====================================================================
+        ivector_widen!($name, $n, i8, i16);
+        ivector_widen!($name, $n, i16, i32);
+        ivector_widen!($name, $n, i32, i64);
+        // ====================================================================
+        ivector_ops!($name, Add, add, AddAssign, add_assign);
+        ivector_ops!($name, Sub, sub, SubAssign, sub_assign);
+        ivector_ops!($name, Mul, mul, MulAssign, mul_assign);
+        ivector_ops!($name, Div, div, DivAssign, div_assign);
+        // ====================================================================
+        impl<I> $name<I>
+        where
+            I: Integer,
+        {
+            // ================================================================
+            /// size
+            pub fn size() -> usize {
+                $n
+            }
+            // ----------------------------------------------------------------
+            /// cast
+            ///
+            /// `None` if a component is out of the range of `J`.
+            pub fn cast<J: Integer>(&self) -> Option<$name<J>> {
+                let mut inner = [J::zero(); $n];
+                for (d, s) in inner.iter_mut().zip(self.0.iter()) {
+                    *d = J::from(*s)?;
+                }
+                Some($name(inner))
+            }
+            // ================================================================
+            /// as_ptr
+            pub fn as_ptr(&self) -> *const I {
+                self.0.as_ptr()
+            }
+            // ----------------------------------------------------------------
+            /// as_mut_ptr
+            pub fn as_mut_ptr(&mut self) -> *mut I {
+                self.0.as_mut_ptr()
+            }
+            // ================================================================
+            /// iter
+            pub fn iter(&self) -> ::core::slice::Iter<'_, I> {
+                self.0.iter()
+            }
+            // ----------------------------------------------------------------
+            /// iter_mut
+            pub fn iter_mut(&mut self) -> ::core::slice::IterMut<'_, I> {
+                self.0.iter_mut()
+            }
+            // ================================================================
+            /// dot
+            pub fn dot(&self, rhs: &Self) -> I {
+                let mut ret = I::zero();
+                for i in 0..$n {
+                    ret += self.0[i] * rhs.0[i]
+                }
+                ret
+            }
+            // ----------------------------------------------------------------
+            /// length2
+            pub fn length2(&self) -> I {
+                self.dot(self)
+            }
+        }
+    };
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+ivector_define!(IVector2, 2);
+// ============================================================================
+impl<I: Integer> IVector2<I> {
+    // ========================================================================
+    /// new
+    pub fn new(x: I, y: I) -> Self {
+        IVector2::<I>([x, y])
+    }
+}
+// ============================================================================
+impl<I: Integer> From<IVector3<I>> for IVector2<I> {
+    fn from(src: IVector3<I>) -> Self {
+        IVector2::new(src[0], src[1])
+    }
+}
+// ============================================================================
+impl<I: Integer> From<IVector4<I>> for IVector2<I> {
+    fn from(src: IVector4<I>) -> Self {
+        IVector2::new(src[0], src[1])
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+ivector_define!(IVector3, 3);
+// ============================================================================
+impl<I: Integer> IVector3<I> {
+    // ========================================================================
+    /// new
+    pub fn new(x: I, y: I, z: I) -> Self {
+        IVector3::<I>([x, y, z])
+    }
+    // ========================================================================
+    /// from_ivector2
+    pub fn from_ivector2(src: &IVector2<I>, z: I) -> Self {
+        IVector3::new(src[0], src[1], z)
+    }
+    // ========================================================================
+    /// cross
+    pub fn cross(&self, r: &Self) -> Self {
+        IVector3::new(
+            self[1] * r[2] - self[2] * r[1],
+            self[2] * r[0] - self[0] * r[2],
+            self[0] * r[1] - self[1] * r[0],
+        )
+    }
+}
+// ============================================================================
+impl<I: Integer> From<IVector4<I>> for IVector3<I> {
+    fn from(src: IVector4<I>) -> Self {
+        IVector3::new(src[0], src[1], src[2])
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+ivector_define!(IVector4, 4);
+// ============================================================================
+impl<I: Integer> IVector4<I> {
+    // ========================================================================
+    /// new
+    pub fn new(x: I, y: I, z: I, w: I) -> Self {
+        IVector4::<I>([x, y, z, w])
+    }
+    // ========================================================================
+    /// from_ivector2
+    pub fn from_ivector2(src: &IVector2<I>, z: I, w: I) -> Self {
+        IVector4::new(src[0], src[1], z, w)
+    }
+    // ========================================================================
+    /// from_ivector3
+    pub fn from_ivector3(src: &IVector3<I>, w: I) -> Self {
+        IVector4::new(src[0], src[1], src[2], w)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    // ========================================================================
+    #[test]
+    fn cast() {
+        let a = IVector3::new(-3i32, 4, 300);
+        assert_eq!(a.cast::<i8>(), None);
+        assert_eq!(a.cast::<i16>(), Some(IVector3::new(-3i16, 4, 300)));
+        let b = IVector2::new(-128i8, 127);
+        assert_eq!(
+            IVector2::<i64>::from(IVector2::<i32>::from(
+                IVector2::<i16>::from(b)
+            )),
+            IVector2::new(-128i64, 127)
+        );
+    }
+    // ========================================================================
+    #[test]
+    fn ops() {
+        let a = IVector3::new(1i32, -2, 3);
+        let b = IVector3::new(4i32, 5, -6);
+        assert_eq!(a + b, IVector3::new(5, 3, -3));
+        assert_eq!(a - b, IVector3::new(-3, -7, 9));
+        assert_eq!(a * 2, IVector3::new(2, -4, 6));
+        assert_eq!(b / 2, IVector3::new(2, 2, -3));
+        assert_eq!(a.dot(&b), -24);
+        assert_eq!(a.cross(&b), IVector3::new(-3, 18, 13));
+        assert_eq!(
+            IVector2::from(IVector4::new(7i64, 8, 9, 10)).length2(),
+            113
+        );
+    }
+}
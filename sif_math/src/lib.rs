@@ -6,7 +6,7 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2018/05/12
-//  @date 2020/08/28
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // attribute  =================================================================
@@ -124,24 +124,62 @@
     unknown_crate_types
 )]
 #![warn(unsafe_code, dead_code, renamed_and_removed_lints)]
+#![no_std]
+// extern  ====================================================================
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
 // mod  =======================================================================
+mod approx;
 mod cleanup;
+mod dual_quaternion;
+mod euler;
 mod float;
 mod integer;
 mod interpolation;
+mod ivector;
 mod matrix;
+mod noise;
+mod projection;
 mod quaternion;
+mod rect;
+mod sampling;
+#[cfg(feature = "simd")]
+mod simd;
+mod spline;
+mod swizzle;
 mod vector;
 // use  =======================================================================
 pub use sif_error::*;
 // ----------------------------------------------------------------------------
+pub use self::approx::ApproxEq;
+#[doc(hidden)]
+pub use self::approx::{
+    assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq,
+};
 pub use self::cleanup::Cleanup;
+pub use self::dual_quaternion::DualQuaternion;
+pub use self::euler::EulerOrder;
 pub use self::float::Float;
 pub use self::integer::Integer;
-pub use self::interpolation::bezier;
+pub use self::interpolation::{
+    bezier, lerp, nlerp, slerp, squad, squad_control,
+};
+pub use self::ivector::{IVector2, IVector3, IVector4};
 pub use self::matrix::{
     Matrix2x2, Matrix2x3, Matrix3x2, Matrix3x3, Matrix3x4, Matrix4x3,
     Matrix4x4,
 };
+pub use self::noise::{Fbm, Noise, Perlin, Simplex, Worley};
 pub use self::quaternion::Quaternion;
+pub use self::rect::{IRect, Rect};
+pub use self::sampling::{
+    halton2, halton3, radical_inverse, sample_cone, sample_disk,
+    sample_hemisphere, sample_hemisphere_cosine, sample_sphere,
+    sample_triangle, sobol2,
+};
+pub use self::spline::{
+    ArcLengthTable, BSpline, BezierPath, CatmullRom, CatmullRomType, Hermite,
+    Spline, SplineVector,
+};
 pub use self::vector::{Vector2, Vector3, Vector4};
@@ -6,11 +6,12 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/04/19
-//  @date 2019/07/09
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
-use std::ops::{
+use alloc::string::String;
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub,
     SubAssign,
 };
@@ -24,6 +25,11 @@ macro_rules! matrix_define {
         // ====================================================================
         /// struct $name
         #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
         pub struct $name<V: Float>([$vector<V>; $n]);
         // ====================================================================
         impl<V> From<[$vector<V>; $n]> for $name<V>
@@ -35,6 +41,16 @@ macro_rules! matrix_define {
                 *m.cleanup()
             }
         }
+        // --------------------------------------------------------------------
+        impl From<$name<f32>> for $name<f64> {
+            fn from(src: $name<f32>) -> Self {
+                let mut inner = [$vector::<f64>::default(); $n];
+                for (d, s) in inner.iter_mut().zip(src.0.iter()) {
+                    *d = $vector::from(*s);
+                }
+                $name(inner)
+            }
+        }
         // ====================================================================
         impl<V> Index<usize> for $name<V>
         where
@@ -155,33 +171,6 @@ macro_rules! matrix_define {
             }
         }
         // ====================================================================
-        impl<V> Mul<$name<V>> for $name<V>
-        where
-            V: Float,
-        {
-            type Output = Self;
-            fn mul(self, rhs: $name<V>) -> Self::Output {
-                let mut r = Self::from_no_clean([$vector::<V>::default(); $n]);
-                for (i, vi) in r.iter_mut().enumerate() {
-                    for (j, vj) in vi.iter_mut().enumerate() {
-                        for (k, s) in self.iter().enumerate() {
-                            *vj += s[j] * rhs[i][k];
-                        }
-                    }
-                }
-                *r.cleanup()
-            }
-        }
-        // --------------------------------------------------------------------
-        impl<V> MulAssign<$name<V>> for $name<V>
-        where
-            V: Float,
-        {
-            fn mul_assign(&mut self, rhs: $name<V>) {
-                *self = *self * rhs;
-            }
-        }
-        // ====================================================================
         impl<V> $name<V>
         where
             V: Float,
@@ -196,6 +185,17 @@ macro_rules! matrix_define {
             pub fn from_no_clean(inner: [$vector<V>; $n]) -> Self {
                 $name(inner)
             }
+            // ----------------------------------------------------------------
+            /// cast
+            ///
+            /// `None` if a component is out of the range of `U`.
+            pub fn cast<U: Float>(&self) -> Option<$name<U>> {
+                let mut inner = [$vector::<U>::default(); $n];
+                for (d, s) in inner.iter_mut().zip(self.0.iter()) {
+                    *d = s.cast()?;
+                }
+                Some($name(inner))
+            }
             // ================================================================
             /// new_identity
             pub fn new_identity() -> Self {
@@ -218,14 +218,14 @@ macro_rules! matrix_define {
             }
             // ================================================================
             /// iter
-            pub fn iter<'a>(&'a self) -> ::std::slice::Iter<'a, $vector<V>> {
+            pub fn iter<'a>(&'a self) -> ::core::slice::Iter<'a, $vector<V>> {
                 self.0.iter()
             }
             // ----------------------------------------------------------------
             /// iter_mut
             pub fn iter_mut<'a>(
                 &'a mut self,
-            ) -> ::std::slice::IterMut<'a, $vector<V>> {
+            ) -> ::core::slice::IterMut<'a, $vector<V>> {
                 self.0.iter_mut()
             }
             // ================================================================
@@ -347,6 +347,101 @@ macro_rules! matrix_define {
                 }
                 Ok(*m.cleanup())
             }
+            // ================================================================
+            /// new_lu
+            ///
+            /// LU decomposition with partial pivoting, `P * self = L * U`.
+            /// The returned matrix holds `U` on and above the diagonal and
+            /// `L` below it, whose unit diagonal is implicit.
+            /// `order[i]` is the row of `self` that moved to row `i`.
+            pub fn new_lu(&self) -> Result<(Self, [usize; $n])> {
+                debug_assert!($n == $vector::<V>::size());
+                let mut m = *self;
+                let mut order = [0usize; $n];
+                for (i, v) in order.iter_mut().enumerate() {
+                    *v = i;
+                }
+                let mut norm = V::zero();
+                for v in self.iter() {
+                    for x in v.iter() {
+                        norm = norm.max(x.abs());
+                    }
+                }
+                let limit = norm * V::epsilon() * V::from($n).unwrap();
+                for o in 0..$n {
+                    let mut d = o;
+                    let mut max = m[o][o].abs();
+                    for r in o + 1..$n {
+                        if max < m[o][r].abs() {
+                            d = r;
+                            max = m[o][r].abs();
+                        }
+                    }
+                    if max <= limit {
+                        return Err(Error::InvalidArgument(String::from(
+                            "::sif_math::matrix::new_lu: singular",
+                        )));
+                    }
+                    if o != d {
+                        for c in 0..$n {
+                            let t = m[c][o];
+                            m[c][o] = m[c][d];
+                            m[c][d] = t;
+                        }
+                        order.swap(o, d);
+                    }
+                    for r in o + 1..$n {
+                        let f = m[o][r] / m[o][o];
+                        m[o][r] = f;
+                        for c in o + 1..$n {
+                            let t = m[c][o];
+                            m[c][r] -= f * t;
+                        }
+                    }
+                }
+                Ok((m, order))
+            }
+            // ----------------------------------------------------------------
+            /// determinant
+            pub fn determinant(&self) -> V {
+                let (lu, order) = match self.new_lu() {
+                    Ok(x) => x,
+                    Err(_) => return V::zero(),
+                };
+                let mut ret = V::one();
+                for i in 0..$n {
+                    ret *= lu[i][i];
+                    for j in i + 1..$n {
+                        if order[j] < order[i] {
+                            ret = -ret;
+                        }
+                    }
+                }
+                ret
+            }
+            // ----------------------------------------------------------------
+            /// solve
+            ///
+            /// Solves `self * x = b` for `x`.
+            pub fn solve(&self, b: &$vector<V>) -> Result<$vector<V>> {
+                let (lu, order) = self.new_lu()?;
+                let mut x = $vector::<V>::default();
+                for i in 0..$n {
+                    x[i] = b[order[i]];
+                    for j in 0..i {
+                        let t = lu[j][i] * x[j];
+                        x[i] -= t;
+                    }
+                }
+                for i in (0..$n).rev() {
+                    for j in i + 1..$n {
+                        let t = lu[j][i] * x[j];
+                        x[i] -= t;
+                    }
+                    x[i] /= lu[i][i];
+                }
+                Ok(x)
+            }
             // ----------------------------------------------------------------
             /// new_lower       *** row_order ***
             pub fn new_lower(&self) -> Self {
@@ -373,6 +468,81 @@ macro_rules! matrix_define {
         }
     };
 }
+// ============================================================================
+/// matrix_mul!
+///
+/// `$lhs * $rhs`, where the column count of `$lhs` equals the row count of
+/// `$rhs`. `$fast` is tried first when the `simd` feature is enabled.
+macro_rules! matrix_mul {
+    ($lhs:ident * $rhs:ident => $out:ident $(, $fast:path)?) => {
+        impl<V> Mul<$rhs<V>> for $lhs<V>
+        where
+            V: Float,
+        {
+            type Output = $out<V>;
+            fn mul(self, rhs: $rhs<V>) -> Self::Output {
+                $(
+                    #[cfg(feature = "simd")]
+                    {
+                        if let Some(r) = $fast(&self, &rhs) {
+                            return r;
+                        }
+                    }
+                )?
+                let mut r = $out::<V>::from_no_clean(Default::default());
+                for (i, vi) in r.iter_mut().enumerate() {
+                    for (j, vj) in vi.iter_mut().enumerate() {
+                        for (k, s) in self.iter().enumerate() {
+                            *vj += s[j] * rhs[i][k];
+                        }
+                    }
+                }
+                *r.cleanup()
+            }
+        }
+    };
+    ($lhs:ident *= $rhs:ident $(, $fast:path)?) => {
+        matrix_mul!($lhs * $rhs => $lhs $(, $fast)?);
+        // --------------------------------------------------------------------
+        impl<V> MulAssign<$rhs<V>> for $lhs<V>
+        where
+            V: Float,
+        {
+            fn mul_assign(&mut self, rhs: $rhs<V>) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+// ============================================================================
+/// matrix_mul_vector!
+macro_rules! matrix_mul_vector {
+    ($name:ident * $in:ident => $out:ident $(, $fast:path)?) => {
+        impl<V> Mul<$in<V>> for $name<V>
+        where
+            V: Float,
+        {
+            type Output = $out<V>;
+            fn mul(self, rhs: $in<V>) -> Self::Output {
+                $(
+                    #[cfg(feature = "simd")]
+                    {
+                        if let Some(r) = $fast(&self, &rhs) {
+                            return r;
+                        }
+                    }
+                )?
+                let mut r = $out::<V>::default();
+                for (j, v) in r.iter_mut().enumerate() {
+                    for (k, s) in self.iter().enumerate() {
+                        *v += s[j] * rhs[k];
+                    }
+                }
+                *r.cleanup()
+            }
+        }
+    };
+}
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 matrix_define!(Matrix2x2(Vector2; 2));
@@ -398,6 +568,83 @@ where
         ])
     }
 }
+// ============================================================================
+impl<V> Matrix3x3<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new_symmetric_eigen
+    ///
+    /// Jacobi eigen decomposition of a symmetric matrix.
+    /// Returns the eigenvalues in descending order and a rotation matrix whose
+    /// columns are the matching unit eigenvectors, so that
+    /// `self = vectors * diag(values) * vectors^T`.
+    pub fn new_symmetric_eigen(&self) -> (Vector3<V>, Self) {
+        // a[row][col]
+        let mut a = [[V::zero(); 3]; 3];
+        for (c, v) in self.iter().enumerate() {
+            for (r, x) in v.iter().enumerate() {
+                a[r][c] = *x;
+            }
+        }
+        let mut e = [[V::zero(); 3]; 3];
+        for (i, v) in e.iter_mut().enumerate() {
+            v[i] = V::one();
+        }
+        let two = V::from(2).unwrap();
+        for _ in 0..64 {
+            let off =
+                a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            let diag =
+                a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+            if off <= diag * V::epsilon() * V::epsilon() {
+                break;
+            }
+            for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == V::zero() {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = theta.signum()
+                    / (theta.abs() + (theta * theta + V::one()).sqrt());
+                let c = V::one() / (t * t + V::one()).sqrt();
+                let s = t * c;
+                for v in &mut a {
+                    let (akp, akq) = (v[p], v[q]);
+                    v[p] = c * akp - s * akq;
+                    v[q] = s * akp + c * akq;
+                }
+                let (ap, aq) = (a[p], a[q]);
+                for (k, (apk, aqk)) in ap.iter().zip(aq.iter()).enumerate() {
+                    a[p][k] = c * *apk - s * *aqk;
+                    a[q][k] = s * *apk + c * *aqk;
+                }
+                for v in &mut e {
+                    let (ep, eq) = (v[p], v[q]);
+                    v[p] = c * ep - s * eq;
+                    v[q] = s * ep + c * eq;
+                }
+            }
+        }
+        let mut idx = [0usize, 1, 2];
+        idx.sort_by(|&i, &j| {
+            a[j][j]
+                .partial_cmp(&a[i][i])
+                .unwrap_or(::core::cmp::Ordering::Equal)
+        });
+        let column = |i: usize| Vector3::new(e[0][i], e[1][i], e[2][i]);
+        let (c0, c1) = (column(idx[0]), column(idx[1]));
+        (
+            Vector3::new(
+                a[idx[0]][idx[0]],
+                a[idx[1]][idx[1]],
+                a[idx[2]][idx[2]],
+            ),
+            Matrix3x3::from_no_clean([c0, c1, c0.cross(&c1)]),
+        )
+    }
+}
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 matrix_define!(Matrix3x4(Vector4; 3));
@@ -441,33 +688,143 @@ where
         ])
     }
 }
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+matrix_mul!(Matrix2x2 *= Matrix2x2);
+matrix_mul!(Matrix2x2 * Matrix3x2 => Matrix3x2);
+matrix_mul!(Matrix2x3 *= Matrix2x2);
+matrix_mul!(Matrix2x3 * Matrix3x2 => Matrix3x3);
+matrix_mul!(Matrix3x2 * Matrix2x3 => Matrix2x2);
+matrix_mul!(Matrix3x2 *= Matrix3x3);
+matrix_mul!(Matrix3x3 * Matrix2x3 => Matrix2x3);
+matrix_mul!(Matrix3x3 *= Matrix3x3);
+matrix_mul!(Matrix3x3 * Matrix4x3 => Matrix4x3);
+matrix_mul!(Matrix3x4 *= Matrix3x3);
+matrix_mul!(Matrix3x4 * Matrix4x3 => Matrix4x4);
+matrix_mul!(Matrix4x3 * Matrix3x4 => Matrix3x3);
+matrix_mul!(Matrix4x3 *= Matrix4x4);
+matrix_mul!(Matrix4x4 * Matrix3x4 => Matrix3x4);
+matrix_mul!(Matrix4x4 *= Matrix4x4, super::simd::mul_matrix4x4);
+// ============================================================================
+matrix_mul_vector!(Matrix2x2 * Vector2 => Vector2);
+matrix_mul_vector!(Matrix2x3 * Vector2 => Vector3);
+matrix_mul_vector!(Matrix3x2 * Vector3 => Vector2);
+matrix_mul_vector!(Matrix3x3 * Vector3 => Vector3);
+matrix_mul_vector!(Matrix3x4 * Vector3 => Vector4);
+matrix_mul_vector!(Matrix4x3 * Vector4 => Vector3);
+matrix_mul_vector!(Matrix4x4 * Vector4 => Vector4, super::simd::mul_vector4);
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+impl<V> From<Matrix3x3<V>> for Matrix2x2<V>
+where
+    V: Float,
+{
+    /// The upper-left 2x2.
+    fn from(m: Matrix3x3<V>) -> Self {
+        Matrix2x2::from_no_clean([Vector2::from(m[0]), Vector2::from(m[1])])
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> From<Matrix2x2<V>> for Matrix3x3<V>
+where
+    V: Float,
+{
+    /// Embeds into the upper-left of the identity.
+    fn from(m: Matrix2x2<V>) -> Self {
+        Matrix3x3::from_no_clean([
+            Vector3::from_vector2(&m[0], V::zero()),
+            Vector3::from_vector2(&m[1], V::zero()),
+            Vector3::new(V::zero(), V::zero(), V::one()),
+        ])
+    }
+}
+// ============================================================================
+impl<V> From<Matrix4x4<V>> for Matrix3x3<V>
+where
+    V: Float,
+{
+    /// The upper-left 3x3.
+    fn from(m: Matrix4x4<V>) -> Self {
+        Matrix3x3::from_no_clean([
+            Vector3::from(m[0]),
+            Vector3::from(m[1]),
+            Vector3::from(m[2]),
+        ])
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> From<Matrix3x3<V>> for Matrix4x4<V>
+where
+    V: Float,
+{
+    /// Embeds into the upper-left of the identity.
+    fn from(m: Matrix3x3<V>) -> Self {
+        Matrix4x4::from_no_clean([
+            Vector4::from_vector3(&m[0], V::zero()),
+            Vector4::from_vector3(&m[1], V::zero()),
+            Vector4::from_vector3(&m[2], V::zero()),
+            Vector4::new(V::zero(), V::zero(), V::zero(), V::one()),
+        ])
+    }
+}
 // ============================================================================
-impl<V> Mul<Vector4<V>> for Matrix4x4<V>
+impl<V> From<Matrix4x4<V>> for Matrix4x3<V>
 where
     V: Float,
 {
-    type Output = Vector4<V>;
-    fn mul(self, rhs: Vector4<V>) -> Self::Output {
-        Vector4::<V>::from([
-            (self[0][0] * rhs[0]
-                + self[1][0] * rhs[1]
-                + self[2][0] * rhs[2]
-                + self[3][0] * rhs[3]),
-            (self[0][1] * rhs[0]
-                + self[1][1] * rhs[1]
-                + self[2][1] * rhs[2]
-                + self[3][1] * rhs[3]),
-            (self[0][2] * rhs[0]
-                + self[1][2] * rhs[1]
-                + self[2][2] * rhs[2]
-                + self[3][2] * rhs[3]),
-            (self[0][3] * rhs[0]
-                + self[1][3] * rhs[1]
-                + self[2][3] * rhs[2]
-                + self[3][3] * rhs[3]),
+    /// Drops the last row of an affine matrix.
+    fn from(m: Matrix4x4<V>) -> Self {
+        Matrix4x3::from_no_clean([
+            Vector3::from(m[0]),
+            Vector3::from(m[1]),
+            Vector3::from(m[2]),
+            Vector3::from(m[3]),
         ])
     }
 }
+// ----------------------------------------------------------------------------
+impl<V> From<Matrix4x3<V>> for Matrix4x4<V>
+where
+    V: Float,
+{
+    /// Appends the row `[0, 0, 0, 1]` of an affine matrix.
+    fn from(m: Matrix4x3<V>) -> Self {
+        Matrix4x4::from_no_clean([
+            Vector4::from_vector3(&m[0], V::zero()),
+            Vector4::from_vector3(&m[1], V::zero()),
+            Vector4::from_vector3(&m[2], V::zero()),
+            Vector4::from_vector3(&m[3], V::one()),
+        ])
+    }
+}
+// ============================================================================
+impl<V> Matrix3x3<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// normal_matrix
+    ///
+    /// The inverse transpose.
+    pub fn normal_matrix(&self) -> Result<Self> {
+        let mut ret = self.new_inverse()?;
+        let _ = ret.transpose();
+        Ok(ret)
+    }
+}
+// ============================================================================
+impl<V> Matrix4x4<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// normal_matrix
+    ///
+    /// The inverse transpose of the upper-left 3x3.
+    pub fn normal_matrix(&self) -> Result<Matrix3x3<V>> {
+        Matrix3x3::from(*self).normal_matrix()
+    }
+}
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 #[cfg(test)]
@@ -476,6 +833,22 @@ mod tests {
     use super::*;
     // ========================================================================
     #[test]
+    fn cast() {
+        let m = Matrix3x3::from([
+            Vector3::new(1.0f64, 2.0, 3.0),
+            Vector3::new(-0.5, 0.25, 0.0),
+            Vector3::new(4.0, 5.0, 6.5),
+        ]);
+        let n = m.cast::<f32>().unwrap();
+        assert_eq!(n[1][0], -0.5f32);
+        assert_eq!(Matrix3x3::<f64>::from(n), m);
+        assert_eq!(n.cast::<f64>(), Some(m));
+        let mut big = m;
+        big[2][1] = 1.0e300;
+        assert!(big.cast::<f32>().is_none());
+    }
+    // ========================================================================
+    #[test]
     fn inverse() {
         let m = Matrix4x4::from([
             Vector4::from_no_clean([1.0f32, 1.0, 1.0, -1.0]),
@@ -501,4 +874,101 @@ mod tests {
         let u = lu.new_upper();
         assert_eq!(m, l * u);
     }
+    // ========================================================================
+    #[test]
+    fn solve() {
+        let m = Matrix4x4::from([
+            Vector4::from_no_clean([0.0f64, 2.0, 1.0, 4.0]),
+            Vector4::from_no_clean([1.0, 0.0, 3.0, -1.0]),
+            Vector4::from_no_clean([2.0, 1.0, 0.0, 5.0]),
+            Vector4::from_no_clean([-3.0, 1.0, 2.0, 0.0]),
+        ]);
+        let x = Vector4::new(1.0f64, -2.0, 0.5, 3.0);
+        let b = m * x;
+        assert!((m.solve(&b).unwrap() - x).length() < 1.0e-12);
+        assert!(Matrix4x4::<f64>::from_no_clean([Vector4::default(); 4])
+            .solve(&b)
+            .is_err());
+    }
+    // ========================================================================
+    #[test]
+    fn determinant() {
+        let m = Matrix3x3::from([
+            Vector3::from_no_clean([0.0f64, 1.0, 2.0]),
+            Vector3::from_no_clean([3.0, 4.0, 5.0]),
+            Vector3::from_no_clean([6.0, 7.0, 9.0]),
+        ]);
+        assert!((m.determinant() + 3.0).abs() < 1.0e-12);
+        let s = Matrix3x3::from([
+            Vector3::from_no_clean([1.0f64, 2.0, 3.0]),
+            Vector3::from_no_clean([2.0, 4.0, 6.0]),
+            Vector3::from_no_clean([0.0, 1.0, 1.0]),
+        ]);
+        assert_eq!(s.determinant(), 0.0);
+    }
+    // ========================================================================
+    #[test]
+    fn mul_dimension() {
+        let a = Matrix2x3::from([
+            Vector3::from_no_clean([1.0f32, 2.0, 3.0]),
+            Vector3::from_no_clean([4.0, 5.0, 6.0]),
+        ]);
+        let b = Matrix3x2::from([
+            Vector2::from_no_clean([1.0f32, 0.0]),
+            Vector2::from_no_clean([0.0, 1.0]),
+            Vector2::from_no_clean([1.0, 1.0]),
+        ]);
+        let ab: Matrix3x3<f32> = a * b;
+        assert_eq!(ab[2], Vector3::new(5.0, 7.0, 9.0));
+        let ba: Matrix2x2<f32> = b * a;
+        assert_eq!(ba[0], Vector2::new(4.0, 5.0));
+        assert_eq!(ba[1], Vector2::new(10.0, 11.0));
+        let v = Vector3::new(2.0f32, -1.0, 0.5);
+        assert_eq!((a * b) * v, a * (b * v));
+    }
+    // ========================================================================
+    #[test]
+    fn normal_matrix() {
+        let mut m = Matrix4x4::<f32>::default();
+        m[0][0] = 2.0;
+        m[1][1] = 4.0;
+        m[3] = Vector4::new(1.0, 2.0, 3.0, 1.0);
+        let n = m.normal_matrix().unwrap();
+        assert_eq!(n[0], Vector3::new(0.5, 0.0, 0.0));
+        assert_eq!(n[1], Vector3::new(0.0, 0.25, 0.0));
+        assert_eq!(Matrix4x4::from(Matrix3x3::from(m))[3][3], 1.0);
+    }
+    // ========================================================================
+    #[test]
+    fn symmetric_eigen() {
+        let m = Matrix3x3::from([
+            Vector3::from_no_clean([4.0f64, 1.0, -2.0]),
+            Vector3::from_no_clean([1.0, 2.0, 0.5]),
+            Vector3::from_no_clean([-2.0, 0.5, 3.0]),
+        ]);
+        let (values, vectors) = m.new_symmetric_eigen();
+        assert!(values[1] <= values[0] && values[2] <= values[1]);
+        assert!((vectors.determinant() - 1.0).abs() < 1.0e-12);
+        for i in 0..3 {
+            let mv = m.solve(&vectors[i]).unwrap() * values[i];
+            assert!((mv - vectors[i]).length() < 1.0e-9);
+        }
+    }
+    // ========================================================================
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut m = Matrix4x4::<f64>::default();
+        m[3] = Vector4::new(1.0, 2.0, 3.0, 1.0);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            json,
+            "[[1.0,0.0,0.0,0.0],[0.0,1.0,0.0,0.0],[0.0,0.0,1.0,0.0],\
+             [1.0,2.0,3.0,1.0]]"
+        );
+        assert_eq!(m, serde_json::from_str::<Matrix4x4<f64>>(&json).unwrap());
+        let bin = bincode::serialize(&m).unwrap();
+        assert_eq!(bin.len(), 128);
+        assert_eq!(m, bincode::deserialize::<Matrix4x4<f64>>(&bin).unwrap());
+    }
 }
@@ -0,0 +1,718 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! noise.rs
+//!
+//! Seeded coherent noise. The tables are built by an own generator and the
+//! evaluation uses only `+`, `-`, `*`, `/`, `sqrt` and `floor`, so that the
+//! same seed yields the same values on every platform.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use core::ops::Mul;
+// ----------------------------------------------------------------------------
+use super::{Float, Vector2, Vector3, Vector4};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// GRAD3
+const GRAD3: [[i8; 3]; 12] = [
+    [1, 1, 0],
+    [-1, 1, 0],
+    [1, -1, 0],
+    [-1, -1, 0],
+    [1, 0, 1],
+    [-1, 0, 1],
+    [1, 0, -1],
+    [-1, 0, -1],
+    [0, 1, 1],
+    [0, -1, 1],
+    [0, 1, -1],
+    [0, -1, -1],
+];
+// ----------------------------------------------------------------------------
+/// GRAD2
+const GRAD2: [[i8; 2]; 8] = [
+    [1, 0],
+    [-1, 0],
+    [0, 1],
+    [0, -1],
+    [1, 1],
+    [-1, 1],
+    [1, -1],
+    [-1, -1],
+];
+// ----------------------------------------------------------------------------
+/// GRAD4
+const GRAD4: [[i8; 4]; 32] = [
+    [0, 1, 1, 1],
+    [0, 1, 1, -1],
+    [0, 1, -1, 1],
+    [0, 1, -1, -1],
+    [0, -1, 1, 1],
+    [0, -1, 1, -1],
+    [0, -1, -1, 1],
+    [0, -1, -1, -1],
+    [1, 0, 1, 1],
+    [1, 0, 1, -1],
+    [1, 0, -1, 1],
+    [1, 0, -1, -1],
+    [-1, 0, 1, 1],
+    [-1, 0, 1, -1],
+    [-1, 0, -1, 1],
+    [-1, 0, -1, -1],
+    [1, 1, 0, 1],
+    [1, 1, 0, -1],
+    [1, -1, 0, 1],
+    [1, -1, 0, -1],
+    [-1, 1, 0, 1],
+    [-1, 1, 0, -1],
+    [-1, -1, 0, 1],
+    [-1, -1, 0, -1],
+    [1, 1, 1, 0],
+    [1, 1, -1, 0],
+    [1, -1, 1, 0],
+    [1, -1, -1, 0],
+    [-1, 1, 1, 0],
+    [-1, 1, -1, 0],
+    [-1, -1, 1, 0],
+    [-1, -1, -1, 0],
+];
+// ============================================================================
+/// splitmix64
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+// ----------------------------------------------------------------------------
+/// dot
+fn dot<V: Float>(g: &[i8], p: &[V]) -> V {
+    g.iter()
+        .zip(p)
+        .fold(V::zero(), |acc, (g, p)| acc + V::from(*g).unwrap() * *p)
+}
+// ----------------------------------------------------------------------------
+/// fade
+fn fade<V: Float>(t: V) -> V {
+    t * t
+        * t
+        * (t * (t * V::from(6).unwrap() - V::from(15).unwrap())
+            + V::from(10).unwrap())
+}
+// ----------------------------------------------------------------------------
+/// cell
+///
+/// The lattice cell of `x`, and the offset in it.
+fn cell<V: Float>(x: V) -> (i64, V) {
+    let f = x.floor();
+    (f.to_i64().unwrap_or(0), x - f)
+}
+// ----------------------------------------------------------------------------
+/// wrap
+fn wrap(i: i64) -> usize {
+    (i & 0xFF) as usize
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait Noise
+pub trait Noise<V: Float, P> {
+    /// noise
+    fn noise(&self, p: &P) -> V;
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Permutation
+#[derive(Debug, Clone)]
+struct Permutation([u8; 512]);
+// ============================================================================
+impl Permutation {
+    // ========================================================================
+    /// new
+    fn new(seed: u64) -> Self {
+        let mut state = seed;
+        let mut p = [0u8; 512];
+        for (i, x) in p.iter_mut().take(256).enumerate() {
+            *x = i as u8;
+        }
+        for i in (1..256).rev() {
+            let j = (splitmix64(&mut state) % (i as u64 + 1)) as usize;
+            p.swap(i, j);
+        }
+        for i in 0..256 {
+            p[256 + i] = p[i];
+        }
+        Permutation(p)
+    }
+    // ========================================================================
+    /// hash
+    fn hash(&self, c: &[usize]) -> usize {
+        c.iter()
+            .rev()
+            .fold(0, |acc, x| usize::from(self.0[x + acc]))
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Perlin
+///
+/// Improved Perlin noise, in about `[-1, 1]`, zero on the lattice.
+#[derive(Debug, Clone)]
+pub struct Perlin {
+    /// perm
+    perm: Permutation,
+}
+// ============================================================================
+impl Perlin {
+    /// new
+    pub fn new(seed: u64) -> Self {
+        Perlin {
+            perm: Permutation::new(seed),
+        }
+    }
+}
+// ============================================================================
+impl<V> Noise<V, Vector2<V>> for Perlin
+where
+    V: Float,
+{
+    fn noise(&self, p: &Vector2<V>) -> V {
+        let (ix, x) = cell(p[0]);
+        let (iy, y) = cell(p[1]);
+        let (ix, iy) = (wrap(ix), wrap(iy));
+        let one = V::one();
+        let g = |i: usize, j: usize, x: V, y: V| {
+            dot(&GRAD2[self.perm.hash(&[ix + i, iy + j]) & 7], &[x, y])
+        };
+        let (u, v) = (fade(x), fade(y));
+        let a = g(0, 0, x, y) + (g(1, 0, x - one, y) - g(0, 0, x, y)) * u;
+        let b = g(0, 1, x, y - one)
+            + (g(1, 1, x - one, y - one) - g(0, 1, x, y - one)) * u;
+        a + (b - a) * v
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Noise<V, Vector3<V>> for Perlin
+where
+    V: Float,
+{
+    fn noise(&self, p: &Vector3<V>) -> V {
+        let (ix, x) = cell(p[0]);
+        let (iy, y) = cell(p[1]);
+        let (iz, z) = cell(p[2]);
+        let (ix, iy, iz) = (wrap(ix), wrap(iy), wrap(iz));
+        let mut n = [V::zero(); 8];
+        for (c, n) in n.iter_mut().enumerate() {
+            let (i, j, k) = (c & 1, (c >> 1) & 1, c >> 2);
+            let h = self.perm.hash(&[ix + i, iy + j, iz + k]);
+            *n = dot(
+                &GRAD3[h % 12],
+                &[
+                    x - V::from(i).unwrap(),
+                    y - V::from(j).unwrap(),
+                    z - V::from(k).unwrap(),
+                ],
+            );
+        }
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+        let lerp = |a: V, b: V, t: V| a + (b - a) * t;
+        let a = lerp(lerp(n[0], n[1], u), lerp(n[2], n[3], u), v);
+        let b = lerp(lerp(n[4], n[5], u), lerp(n[6], n[7], u), v);
+        lerp(a, b, w)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Simplex
+///
+/// Simplex noise, in about `[-1, 1]`.
+#[derive(Debug, Clone)]
+pub struct Simplex {
+    /// perm
+    perm: Permutation,
+}
+// ============================================================================
+impl Simplex {
+    /// new
+    pub fn new(seed: u64) -> Self {
+        Simplex {
+            perm: Permutation::new(seed),
+        }
+    }
+}
+// ============================================================================
+/// corner
+///
+/// The contribution of a corner at the offset `p`.
+fn corner<V: Float>(r2: V, g: &[i8], p: &[V]) -> V {
+    let t = p.iter().fold(r2, |acc, x| acc - *x * *x);
+    if t < V::zero() {
+        V::zero()
+    } else {
+        let t2 = t * t;
+        t2 * t2 * dot(g, p)
+    }
+}
+// ============================================================================
+impl<V> Noise<V, Vector2<V>> for Simplex
+where
+    V: Float,
+{
+    fn noise(&self, p: &Vector2<V>) -> V {
+        let three = V::from(3).unwrap();
+        let f2 = V::from(0.5).unwrap() * (three.sqrt() - V::one());
+        let g2 = (three - three.sqrt()) / V::from(6).unwrap();
+        let s = (p[0] + p[1]) * f2;
+        let (i, j) = ((p[0] + s).floor(), (p[1] + s).floor());
+        let t = (i + j) * g2;
+        let x0 = [p[0] - (i - t), p[1] - (j - t)];
+        let (i1, j1) = if x0[0] > x0[1] { (1, 0) } else { (0, 1) };
+        let x1 = [
+            x0[0] - V::from(i1).unwrap() + g2,
+            x0[1] - V::from(j1).unwrap() + g2,
+        ];
+        let g = g2 + g2 - V::one();
+        let x2 = [x0[0] + g, x0[1] + g];
+        let (ii, jj) =
+            (wrap(i.to_i64().unwrap_or(0)), wrap(j.to_i64().unwrap_or(0)));
+        let grad = |di: usize, dj: usize| {
+            &GRAD3[self.perm.hash(&[ii + di, jj + dj]) % 12][..2]
+        };
+        let r2 = V::from(0.5).unwrap();
+        V::from(70).unwrap()
+            * (corner(r2, grad(0, 0), &x0)
+                + corner(r2, grad(i1, j1), &x1)
+                + corner(r2, grad(1, 1), &x2))
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Noise<V, Vector3<V>> for Simplex
+where
+    V: Float,
+{
+    fn noise(&self, p: &Vector3<V>) -> V {
+        let f3 = V::one() / V::from(3).unwrap();
+        let g3 = V::one() / V::from(6).unwrap();
+        let s = (p[0] + p[1] + p[2]) * f3;
+        let c = [(p[0] + s).floor(), (p[1] + s).floor(), (p[2] + s).floor()];
+        let t = (c[0] + c[1] + c[2]) * g3;
+        let x0 = [p[0] - (c[0] - t), p[1] - (c[1] - t), p[2] - (c[2] - t)];
+        // the simplex is found by ranking the coordinates
+        let rank = rank(&x0);
+        let ijk = |n: usize| -> [usize; 3] {
+            [
+                usize::from(rank[0] >= n),
+                usize::from(rank[1] >= n),
+                usize::from(rank[2] >= n),
+            ]
+        };
+        let base = [
+            wrap(c[0].to_i64().unwrap_or(0)),
+            wrap(c[1].to_i64().unwrap_or(0)),
+            wrap(c[2].to_i64().unwrap_or(0)),
+        ];
+        let r2 = V::from(0.6).unwrap();
+        let mut sum = V::zero();
+        for (n, o) in [[0, 0, 0], ijk(2), ijk(1), [1, 1, 1]].iter().enumerate()
+        {
+            let g = g3 * V::from(n).unwrap();
+            let x = [
+                x0[0] - V::from(o[0]).unwrap() + g,
+                x0[1] - V::from(o[1]).unwrap() + g,
+                x0[2] - V::from(o[2]).unwrap() + g,
+            ];
+            let h = self.perm.hash(&[
+                base[0] + o[0],
+                base[1] + o[1],
+                base[2] + o[2],
+            ]);
+            sum += corner(r2, &GRAD3[h % 12], &x);
+        }
+        V::from(32).unwrap() * sum
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Noise<V, Vector4<V>> for Simplex
+where
+    V: Float,
+{
+    fn noise(&self, p: &Vector4<V>) -> V {
+        let five = V::from(5).unwrap();
+        let f4 = (five.sqrt() - V::one()) / V::from(4).unwrap();
+        let g4 = (five - five.sqrt()) / V::from(20).unwrap();
+        let s = (p[0] + p[1] + p[2] + p[3]) * f4;
+        let c = [
+            (p[0] + s).floor(),
+            (p[1] + s).floor(),
+            (p[2] + s).floor(),
+            (p[3] + s).floor(),
+        ];
+        let t = (c[0] + c[1] + c[2] + c[3]) * g4;
+        let x0 = [
+            p[0] - (c[0] - t),
+            p[1] - (c[1] - t),
+            p[2] - (c[2] - t),
+            p[3] - (c[3] - t),
+        ];
+        let rank = rank(&x0);
+        let ijkl = |n: usize| -> [usize; 4] {
+            [
+                usize::from(rank[0] >= n),
+                usize::from(rank[1] >= n),
+                usize::from(rank[2] >= n),
+                usize::from(rank[3] >= n),
+            ]
+        };
+        let base = [
+            wrap(c[0].to_i64().unwrap_or(0)),
+            wrap(c[1].to_i64().unwrap_or(0)),
+            wrap(c[2].to_i64().unwrap_or(0)),
+            wrap(c[3].to_i64().unwrap_or(0)),
+        ];
+        let r2 = V::from(0.6).unwrap();
+        let mut sum = V::zero();
+        for (n, o) in [[0, 0, 0, 0], ijkl(3), ijkl(2), ijkl(1), [1, 1, 1, 1]]
+            .iter()
+            .enumerate()
+        {
+            let g = g4 * V::from(n).unwrap();
+            let x = [
+                x0[0] - V::from(o[0]).unwrap() + g,
+                x0[1] - V::from(o[1]).unwrap() + g,
+                x0[2] - V::from(o[2]).unwrap() + g,
+                x0[3] - V::from(o[3]).unwrap() + g,
+            ];
+            let h = self.perm.hash(&[
+                base[0] + o[0],
+                base[1] + o[1],
+                base[2] + o[2],
+                base[3] + o[3],
+            ]);
+            sum += corner(r2, &GRAD4[h % 32], &x);
+        }
+        V::from(27).unwrap() * sum
+    }
+}
+// ----------------------------------------------------------------------------
+/// rank
+///
+/// The number of the other coordinates each coordinate is larger than.
+fn rank<V: Float, const N: usize>(x: &[V; N]) -> [usize; N] {
+    let mut r = [0; N];
+    for i in 0..N {
+        for j in (i + 1)..N {
+            if x[i] > x[j] {
+                r[i] += 1;
+            } else {
+                r[j] += 1;
+            }
+        }
+    }
+    r
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Worley
+///
+/// Worley / cellular noise, with one feature point in each unit cell.
+/// `noise` is the distance to the nearest feature point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Worley {
+    /// seed
+    seed: u64,
+}
+// ============================================================================
+impl Worley {
+    // ========================================================================
+    /// new
+    pub fn new(seed: u64) -> Self {
+        Worley { seed }
+    }
+    // ========================================================================
+    /// feature
+    ///
+    /// The feature point of the cell `c`, in `[0, 1)` of the cell.
+    fn feature<V: Float>(&self, c: &[i64]) -> [V; 3] {
+        let mut state = self.seed;
+        for x in c {
+            state = splitmix64(&mut state) ^ (*x as u64);
+        }
+        let h = splitmix64(&mut state);
+        let unit = V::one() / V::from(1u64 << 21).unwrap();
+        [
+            V::from(h & 0x1F_FFFF).unwrap() * unit,
+            V::from((h >> 21) & 0x1F_FFFF).unwrap() * unit,
+            V::from((h >> 42) & 0x1F_FFFF).unwrap() * unit,
+        ]
+    }
+    // ========================================================================
+    /// distances2
+    ///
+    /// The distances to the nearest and the second nearest feature points.
+    pub fn distances2<V: Float>(&self, p: &Vector2<V>) -> (V, V) {
+        let (ix, x) = cell(p[0]);
+        let (iy, y) = cell(p[1]);
+        let mut d = (V::infinity(), V::infinity());
+        for j in -1..=1 {
+            for i in -1..=1 {
+                let f: [V; 3] = self.feature(&[ix + i, iy + j]);
+                let dx = V::from(i).unwrap() + f[0] - x;
+                let dy = V::from(j).unwrap() + f[1] - y;
+                d = Self::nearest(d, dx * dx + dy * dy);
+            }
+        }
+        (d.0.sqrt(), d.1.sqrt())
+    }
+    // ------------------------------------------------------------------------
+    /// distances3
+    ///
+    /// The distances to the nearest and the second nearest feature points.
+    pub fn distances3<V: Float>(&self, p: &Vector3<V>) -> (V, V) {
+        let (ix, x) = cell(p[0]);
+        let (iy, y) = cell(p[1]);
+        let (iz, z) = cell(p[2]);
+        let mut d = (V::infinity(), V::infinity());
+        for k in -1..=1 {
+            for j in -1..=1 {
+                for i in -1..=1 {
+                    let f: [V; 3] = self.feature(&[ix + i, iy + j, iz + k]);
+                    let dx = V::from(i).unwrap() + f[0] - x;
+                    let dy = V::from(j).unwrap() + f[1] - y;
+                    let dz = V::from(k).unwrap() + f[2] - z;
+                    d = Self::nearest(d, dx * dx + dy * dy + dz * dz);
+                }
+            }
+        }
+        (d.0.sqrt(), d.1.sqrt())
+    }
+    // ------------------------------------------------------------------------
+    /// nearest
+    fn nearest<V: Float>(d: (V, V), x: V) -> (V, V) {
+        if x < d.0 {
+            (x, d.0)
+        } else if x < d.1 {
+            (d.0, x)
+        } else {
+            d
+        }
+    }
+}
+// ============================================================================
+impl<V> Noise<V, Vector2<V>> for Worley
+where
+    V: Float,
+{
+    fn noise(&self, p: &Vector2<V>) -> V {
+        self.distances2(p).0
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Noise<V, Vector3<V>> for Worley
+where
+    V: Float,
+{
+    fn noise(&self, p: &Vector3<V>) -> V {
+        self.distances3(p).0
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Fbm
+///
+/// Fractal Brownian motion, the sum of `octaves` octaves of `noise`, each
+/// `lacunarity` times the frequency and `gain` times the amplitude of the
+/// previous one. The sum is divided by the total amplitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fbm<V: Float, N> {
+    /// noise
+    noise: N,
+    /// octaves
+    octaves: usize,
+    /// lacunarity
+    lacunarity: V,
+    /// gain
+    gain: V,
+}
+// ============================================================================
+impl<V, N> Fbm<V, N>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    pub fn new(noise: N, octaves: usize, lacunarity: V, gain: V) -> Self {
+        Fbm {
+            noise,
+            octaves,
+            lacunarity,
+            gain,
+        }
+    }
+    // ========================================================================
+    /// sum
+    fn sum<P, F>(&self, p: &P, f: F) -> V
+    where
+        P: Copy + Mul<V, Output = P>,
+        F: Fn(&N, &P) -> V,
+    {
+        let (mut sum, mut total) = (V::zero(), V::zero());
+        let (mut amplitude, mut frequency) = (V::one(), V::one());
+        for _ in 0..self.octaves {
+            sum += f(&self.noise, &(*p * frequency)) * amplitude;
+            total += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+        if total > V::zero() {
+            sum / total
+        } else {
+            V::zero()
+        }
+    }
+    // ========================================================================
+    /// turbulence
+    ///
+    /// The sum of the absolute values of the octaves, in `[0, 1]` for a noise
+    /// in `[-1, 1]`.
+    pub fn turbulence<P>(&self, p: &P) -> V
+    where
+        N: Noise<V, P>,
+        P: Copy + Mul<V, Output = P>,
+    {
+        self.sum(p, |n, p| n.noise(p).abs())
+    }
+}
+// ============================================================================
+impl<V, N, P> Noise<V, P> for Fbm<V, N>
+where
+    V: Float,
+    N: Noise<V, P>,
+    P: Copy + Mul<V, Output = P>,
+{
+    fn noise(&self, p: &P) -> V {
+        self.sum(p, |n, p| n.noise(p))
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use alloc::vec::Vec;
+    // ========================================================================
+    fn grid<P>(f: impl Fn(f64, f64, f64) -> P) -> Vec<P> {
+        let mut v = Vec::new();
+        for i in 0..1000 {
+            let t = f64::from(i) * 0.173 - 80.0;
+            v.push(f(t, t * 0.71 + 3.1, 12.0 - t * 1.37));
+        }
+        v
+    }
+    // ------------------------------------------------------------------------
+    fn check<P, N: Noise<f64, P>>(n: &N, ps: &[P], lo: f64, hi: f64) -> f64 {
+        let mut sum = 0.0;
+        for p in ps {
+            let x = n.noise(p);
+            assert!(lo <= x && x <= hi, "{}", x);
+            sum += x;
+        }
+        sum
+    }
+    // ========================================================================
+    #[test]
+    fn perlin() {
+        let a = Perlin::new(7);
+        let p2 = grid(|x, y, _| Vector2::new(x, y));
+        let p3 = grid(Vector3::new);
+        let s = check(&a, &p2, -1.0, 1.0);
+        assert_eq!(s, check(&Perlin::new(7), &p2, -1.0, 1.0));
+        assert_ne!(s, check(&Perlin::new(8), &p2, -1.0, 1.0));
+        let _ = check(&a, &p3, -1.0, 1.0);
+        for p in &[Vector3::new(0.0, 0.0, 0.0), Vector3::new(-3.0, 5.0, 9.0)] {
+            assert_eq!(Noise::<f64, _>::noise(&a, p), 0.0);
+        }
+        // continuity across a cell boundary
+        let (l, r) = (
+            Vector3::new(0.999_999, 0.3, 0.6),
+            Vector3::new(1.0, 0.3, 0.6),
+        );
+        let d: f64 = a.noise(&l) - a.noise(&r);
+        assert!(d.abs() < 1.0e-5);
+        // the gradients along x and y balance over a period of the lattice,
+        // where the slope is the gradient itself
+        let (h, mut gx, mut gy) = (1.0e-6, 0.0, 0.0);
+        for i in 0..256 {
+            for j in 0..256 {
+                let p = Vector2::new(f64::from(i), f64::from(j));
+                let dx: f64 = a.noise(&Vector2::new(p[0] + h, p[1]));
+                let dy: f64 = a.noise(&Vector2::new(p[0], p[1] + h));
+                gx += (dx / h).abs();
+                gy += (dy / h).abs();
+            }
+        }
+        assert!((gx / gy - 1.0).abs() < 1.0e-3, "{} {}", gx, gy);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn simplex() {
+        let a = Simplex::new(42);
+        let p2 = grid(|x, y, _| Vector2::new(x, y));
+        let p3 = grid(Vector3::new);
+        let p4 = grid(|x, y, z| Vector4::new(x, y, z, x - z));
+        let s2 = check(&a, &p2, -1.0, 1.0);
+        assert_eq!(s2, check(&Simplex::new(42), &p2, -1.0, 1.0));
+        let s3 = check(&a, &p3, -1.0, 1.0);
+        assert_eq!(s3, check(&Simplex::new(42), &p3, -1.0, 1.0));
+        let s4 = check(&a, &p4, -1.0, 1.0);
+        assert_ne!(s4, check(&Simplex::new(43), &p4, -1.0, 1.0));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn worley() {
+        let a = Worley::new(3);
+        let p2 = grid(|x, y, _| Vector2::new(x, y));
+        let p3 = grid(Vector3::new);
+        let _ = check(&a, &p2, 0.0, 2.0f64.sqrt());
+        let _ = check(&a, &p3, 0.0, 3.0f64.sqrt());
+        for p in &p2 {
+            let (f1, f2) = a.distances2(p);
+            assert!(f1 <= f2);
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn fbm() {
+        let p3 = grid(Vector3::new);
+        let a = Fbm::new(Simplex::new(1), 5, 2.0, 0.5);
+        let _ = check(&a, &p3, -1.0, 1.0);
+        for p in &p3 {
+            let t = a.turbulence(p);
+            assert!((0.0..=1.0).contains(&t));
+        }
+        // one octave is the noise itself
+        let b = Fbm::new(Perlin::new(1), 1, 2.0, 0.5);
+        let p = Vector2::new(0.3, 0.7);
+        assert_eq!(
+            Noise::<f64, _>::noise(&b, &p),
+            Noise::<f64, _>::noise(&Perlin::new(1), &p)
+        );
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn reproducible() {
+        // golden values, the same on every platform
+        let p = Vector3::new(1.25f64, -2.5, 3.75);
+        let q = Vector4::new(1.25f64, -2.5, 3.75, 0.5);
+        assert_eq!(Perlin::new(0).noise(&p), 2.076_182_365_417_480_5e-1);
+        assert_eq!(Simplex::new(0).noise(&p), 7.288_891_460_905_338e-2);
+        assert_eq!(Simplex::new(0).noise(&q), -6.551_625_147_895_825e-2);
+        assert_eq!(Worley::new(0).noise(&p), 5.068_159_852_047_622e-1);
+    }
+}
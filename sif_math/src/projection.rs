@@ -0,0 +1,456 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! projection.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use alloc::string::String;
+// ----------------------------------------------------------------------------
+use super::{Error, Float, Matrix4x4, Result, Vector3, Vector4};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// perspective
+///
+/// `x' = a * x + sx * z`, `y' = b * y + sy * z`, `z' = c * z + d`,
+/// `w' = -z`
+fn perspective<V: Float>(
+    a: V,
+    b: V,
+    sx: V,
+    sy: V,
+    c: V,
+    d: V,
+) -> Matrix4x4<V> {
+    Matrix4x4::from([
+        Vector4::from_no_clean([a, V::zero(), V::zero(), V::zero()]),
+        Vector4::from_no_clean([V::zero(), b, V::zero(), V::zero()]),
+        Vector4::from_no_clean([sx, sy, c, -V::one()]),
+        Vector4::from_no_clean([V::zero(), V::zero(), d, V::zero()]),
+    ])
+}
+// ----------------------------------------------------------------------------
+/// inverse_perspective
+fn inverse_perspective<V: Float>(
+    a: V,
+    b: V,
+    sx: V,
+    sy: V,
+    c: V,
+    d: V,
+) -> Matrix4x4<V> {
+    Matrix4x4::from([
+        Vector4::from_no_clean([
+            V::one() / a,
+            V::zero(),
+            V::zero(),
+            V::zero(),
+        ]),
+        Vector4::from_no_clean([
+            V::zero(),
+            V::one() / b,
+            V::zero(),
+            V::zero(),
+        ]),
+        Vector4::from_no_clean([
+            V::zero(),
+            V::zero(),
+            V::zero(),
+            V::one() / d,
+        ]),
+        Vector4::from_no_clean([sx / a, sy / b, -V::one(), c / d]),
+    ])
+}
+// ----------------------------------------------------------------------------
+/// look_at_basis
+fn look_at_basis<V: Float>(
+    eye: &Vector3<V>,
+    center: &Vector3<V>,
+    up: &Vector3<V>,
+) -> Result<[Vector3<V>; 3]> {
+    let mut f = *center - *eye;
+    let mut s = f.cross(up);
+    if f.length() < V::epsilon() || s.length() < V::epsilon() {
+        return Err(Error::InvalidArgument(String::from(
+            "::sif_math::projection::look_at",
+        )));
+    }
+    let _ = f.normalize();
+    let _ = s.normalize();
+    Ok([s, s.cross(&f), f])
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// Right-handed, looking down the negative z axis. Unless noted otherwise the
+/// projections map depth to `[-1, 1]` as OpenGL does.
+impl<V> Matrix4x4<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new_look_at
+    pub fn new_look_at(
+        eye: &Vector3<V>,
+        center: &Vector3<V>,
+        up: &Vector3<V>,
+    ) -> Result<Self> {
+        let [s, u, f] = look_at_basis(eye, center, up)?;
+        Ok(Matrix4x4::from([
+            Vector4::from_no_clean([s[0], u[0], -f[0], V::zero()]),
+            Vector4::from_no_clean([s[1], u[1], -f[1], V::zero()]),
+            Vector4::from_no_clean([s[2], u[2], -f[2], V::zero()]),
+            Vector4::from_no_clean([
+                -s.dot(eye),
+                -u.dot(eye),
+                f.dot(eye),
+                V::one(),
+            ]),
+        ]))
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse_look_at
+    pub fn new_inverse_look_at(
+        eye: &Vector3<V>,
+        center: &Vector3<V>,
+        up: &Vector3<V>,
+    ) -> Result<Self> {
+        let [s, u, f] = look_at_basis(eye, center, up)?;
+        Ok(Matrix4x4::from([
+            Vector4::from_vector3(&s, V::zero()),
+            Vector4::from_vector3(&u, V::zero()),
+            Vector4::from_vector3(&(f * -V::one()), V::zero()),
+            Vector4::from_vector3(eye, V::one()),
+        ]))
+    }
+    // ========================================================================
+    /// new_frustum
+    ///
+    /// Off-center perspective, as `glFrustum`.
+    pub fn new_frustum(
+        left: V,
+        right: V,
+        bottom: V,
+        top: V,
+        near: V,
+        far: V,
+    ) -> Self {
+        let two = V::from(2).unwrap();
+        perspective(
+            two * near / (right - left),
+            two * near / (top - bottom),
+            (right + left) / (right - left),
+            (top + bottom) / (top - bottom),
+            (far + near) / (near - far),
+            two * far * near / (near - far),
+        )
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse_frustum
+    pub fn new_inverse_frustum(
+        left: V,
+        right: V,
+        bottom: V,
+        top: V,
+        near: V,
+        far: V,
+    ) -> Self {
+        let two = V::from(2).unwrap();
+        inverse_perspective(
+            two * near / (right - left),
+            two * near / (top - bottom),
+            (right + left) / (right - left),
+            (top + bottom) / (top - bottom),
+            (far + near) / (near - far),
+            two * far * near / (near - far),
+        )
+    }
+    // ========================================================================
+    /// new_perspective
+    ///
+    /// `fovy` is the vertical field of view in radians and `aspect` is the
+    /// width divided by the height.
+    pub fn new_perspective(fovy: V, aspect: V, near: V, far: V) -> Self {
+        let top = near * (fovy / V::from(2).unwrap()).tan();
+        let right = top * aspect;
+        Matrix4x4::new_frustum(-right, right, -top, top, near, far)
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse_perspective
+    pub fn new_inverse_perspective(
+        fovy: V,
+        aspect: V,
+        near: V,
+        far: V,
+    ) -> Self {
+        let top = near * (fovy / V::from(2).unwrap()).tan();
+        let right = top * aspect;
+        Matrix4x4::new_inverse_frustum(-right, right, -top, top, near, far)
+    }
+    // ========================================================================
+    /// new_perspective_infinite
+    ///
+    /// `new_perspective` with the far plane at infinity.
+    pub fn new_perspective_infinite(fovy: V, aspect: V, near: V) -> Self {
+        let f = V::one() / (fovy / V::from(2).unwrap()).tan();
+        perspective(
+            f / aspect,
+            f,
+            V::zero(),
+            V::zero(),
+            -V::one(),
+            -V::from(2).unwrap() * near,
+        )
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse_perspective_infinite
+    pub fn new_inverse_perspective_infinite(
+        fovy: V,
+        aspect: V,
+        near: V,
+    ) -> Self {
+        let f = V::one() / (fovy / V::from(2).unwrap()).tan();
+        inverse_perspective(
+            f / aspect,
+            f,
+            V::zero(),
+            V::zero(),
+            -V::one(),
+            -V::from(2).unwrap() * near,
+        )
+    }
+    // ========================================================================
+    /// new_perspective_reversed_z
+    ///
+    /// Maps depth to `[0, 1]` with the near plane at `1` and the far plane at
+    /// `0`, for use with a `[0, 1]` clip depth range.
+    pub fn new_perspective_reversed_z(
+        fovy: V,
+        aspect: V,
+        near: V,
+        far: V,
+    ) -> Self {
+        let f = V::one() / (fovy / V::from(2).unwrap()).tan();
+        perspective(
+            f / aspect,
+            f,
+            V::zero(),
+            V::zero(),
+            near / (far - near),
+            far * near / (far - near),
+        )
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse_perspective_reversed_z
+    pub fn new_inverse_perspective_reversed_z(
+        fovy: V,
+        aspect: V,
+        near: V,
+        far: V,
+    ) -> Self {
+        let f = V::one() / (fovy / V::from(2).unwrap()).tan();
+        inverse_perspective(
+            f / aspect,
+            f,
+            V::zero(),
+            V::zero(),
+            near / (far - near),
+            far * near / (far - near),
+        )
+    }
+    // ------------------------------------------------------------------------
+    /// new_perspective_reversed_z_infinite
+    ///
+    /// `new_perspective_reversed_z` with the far plane at infinity.
+    pub fn new_perspective_reversed_z_infinite(
+        fovy: V,
+        aspect: V,
+        near: V,
+    ) -> Self {
+        let f = V::one() / (fovy / V::from(2).unwrap()).tan();
+        perspective(f / aspect, f, V::zero(), V::zero(), V::zero(), near)
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse_perspective_reversed_z_infinite
+    pub fn new_inverse_perspective_reversed_z_infinite(
+        fovy: V,
+        aspect: V,
+        near: V,
+    ) -> Self {
+        let f = V::one() / (fovy / V::from(2).unwrap()).tan();
+        inverse_perspective(
+            f / aspect,
+            f,
+            V::zero(),
+            V::zero(),
+            V::zero(),
+            near,
+        )
+    }
+    // ========================================================================
+    /// new_ortho
+    ///
+    /// Off-center orthographic, as `glOrtho`.
+    pub fn new_ortho(
+        left: V,
+        right: V,
+        bottom: V,
+        top: V,
+        near: V,
+        far: V,
+    ) -> Self {
+        let two = V::from(2).unwrap();
+        Matrix4x4::from([
+            Vector4::from_no_clean([
+                two / (right - left),
+                V::zero(),
+                V::zero(),
+                V::zero(),
+            ]),
+            Vector4::from_no_clean([
+                V::zero(),
+                two / (top - bottom),
+                V::zero(),
+                V::zero(),
+            ]),
+            Vector4::from_no_clean([
+                V::zero(),
+                V::zero(),
+                two / (near - far),
+                V::zero(),
+            ]),
+            Vector4::from_no_clean([
+                (right + left) / (left - right),
+                (top + bottom) / (bottom - top),
+                (far + near) / (near - far),
+                V::one(),
+            ]),
+        ])
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse_ortho
+    pub fn new_inverse_ortho(
+        left: V,
+        right: V,
+        bottom: V,
+        top: V,
+        near: V,
+        far: V,
+    ) -> Self {
+        let two = V::from(2).unwrap();
+        Matrix4x4::from([
+            Vector4::from_no_clean([
+                (right - left) / two,
+                V::zero(),
+                V::zero(),
+                V::zero(),
+            ]),
+            Vector4::from_no_clean([
+                V::zero(),
+                (top - bottom) / two,
+                V::zero(),
+                V::zero(),
+            ]),
+            Vector4::from_no_clean([
+                V::zero(),
+                V::zero(),
+                (near - far) / two,
+                V::zero(),
+            ]),
+            Vector4::from_no_clean([
+                (right + left) / two,
+                (top + bottom) / two,
+                -(far + near) / two,
+                V::one(),
+            ]),
+        ])
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    // ========================================================================
+    fn is_identity(m: &Matrix4x4<f64>) -> bool {
+        let i = Matrix4x4::<f64>::new_identity();
+        m.iter()
+            .zip(i.iter())
+            .all(|(a, b)| (*a - *b).length() < 1.0e-9)
+    }
+    // ------------------------------------------------------------------------
+    fn ndc(m: &Matrix4x4<f64>, p: &Vector3<f64>) -> Vector3<f64> {
+        let v = *m * Vector4::from_vector3(p, 1.0);
+        Vector3::from(v) / v[3]
+    }
+    // ========================================================================
+    #[test]
+    fn inverse() {
+        let (l, r, b, t, n, f) = (-0.3, 0.5, -0.2, 0.4, 0.1, 50.0);
+        let pairs = [
+            (
+                Matrix4x4::new_frustum(l, r, b, t, n, f),
+                Matrix4x4::new_inverse_frustum(l, r, b, t, n, f),
+            ),
+            (
+                Matrix4x4::new_perspective(1.0, 1.5, n, f),
+                Matrix4x4::new_inverse_perspective(1.0, 1.5, n, f),
+            ),
+            (
+                Matrix4x4::new_perspective_infinite(1.0, 1.5, n),
+                Matrix4x4::new_inverse_perspective_infinite(1.0, 1.5, n),
+            ),
+            (
+                Matrix4x4::new_perspective_reversed_z(1.0, 1.5, n, f),
+                Matrix4x4::new_inverse_perspective_reversed_z(1.0, 1.5, n, f),
+            ),
+            (
+                Matrix4x4::new_perspective_reversed_z_infinite(1.0, 1.5, n),
+                Matrix4x4::new_inverse_perspective_reversed_z_infinite(
+                    1.0, 1.5, n,
+                ),
+            ),
+            (
+                Matrix4x4::new_ortho(l, r, b, t, n, f),
+                Matrix4x4::new_inverse_ortho(l, r, b, t, n, f),
+            ),
+        ];
+        for (m, i) in pairs.iter() {
+            assert!(is_identity(&(*m * *i)));
+            assert!(is_identity(&(*i * *m)));
+        }
+    }
+    // ========================================================================
+    #[test]
+    fn depth() {
+        let (n, f) = (0.5, 20.0);
+        let m = Matrix4x4::new_perspective(1.0, 1.0, n, f);
+        assert!((ndc(&m, &Vector3::new(0.0, 0.0, -n))[2] + 1.0).abs() < 1e-9);
+        assert!((ndc(&m, &Vector3::new(0.0, 0.0, -f))[2] - 1.0).abs() < 1e-9);
+        let r = Matrix4x4::new_perspective_reversed_z(1.0, 1.0, n, f);
+        assert!((ndc(&r, &Vector3::new(0.0, 0.0, -n))[2] - 1.0).abs() < 1e-9);
+        assert!(ndc(&r, &Vector3::new(0.0, 0.0, -f))[2].abs() < 1e-9);
+        let o = Matrix4x4::new_ortho(-1.0, 3.0, -2.0, 2.0, n, f);
+        let p = ndc(&o, &Vector3::new(3.0, -2.0, -f));
+        assert!((p - Vector3::new(1.0, -1.0, 1.0)).length() < 1e-9);
+    }
+    // ========================================================================
+    #[test]
+    fn look_at() {
+        let eye = Vector3::new(1.0, 2.0, 3.0);
+        let center = Vector3::new(4.0, 2.0, -1.0);
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let m = Matrix4x4::new_look_at(&eye, &center, &up).unwrap();
+        let i = Matrix4x4::new_inverse_look_at(&eye, &center, &up).unwrap();
+        assert!(is_identity(&(m * i)));
+        assert!(ndc(&m, &eye).length() < 1e-9);
+        let c = ndc(&m, &center);
+        assert!((c - Vector3::new(0.0, 0.0, -5.0)).length() < 1e-9);
+        assert!(Matrix4x4::new_look_at(&eye, &eye, &up).is_err());
+    }
+}
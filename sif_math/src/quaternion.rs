@@ -6,17 +6,25 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/04/19
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
-use std::ops::{Index, IndexMut};
+use alloc::string::String;
+use core::ops::{Add, Index, IndexMut, Mul, MulAssign, Neg, Sub};
 // ----------------------------------------------------------------------------
-use super::{Float, Vector4};
+use super::{Error, Float, Matrix3x3, Matrix4x4, Result, Vector3, Vector4};
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// struct Quaternion
+///
+/// The components are stored as `[x, y, z, w]`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Quaternion<V: Float>(Vector4<V>);
 // ============================================================================
 impl<V> Default for Quaternion<V>
@@ -45,6 +53,105 @@ where
         *Quaternion(inner).cleanup()
     }
 }
+// ----------------------------------------------------------------------------
+impl From<Quaternion<f32>> for Quaternion<f64> {
+    fn from(src: Quaternion<f32>) -> Self {
+        Quaternion(Vector4::from(src.0))
+    }
+}
+// ============================================================================
+impl<V> From<Matrix3x3<V>> for Quaternion<V>
+where
+    V: Float,
+{
+    /// The matrix must be a pure rotation.
+    fn from(m: Matrix3x3<V>) -> Self {
+        let two = V::from(2).unwrap();
+        let quarter = V::from(0.25).unwrap();
+        // m[col][row]
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if V::zero() < trace {
+            let s = (trace + V::one()).sqrt() * two;
+            [
+                (m[1][2] - m[2][1]) / s,
+                (m[2][0] - m[0][2]) / s,
+                (m[0][1] - m[1][0]) / s,
+                quarter * s,
+            ]
+        } else if m[1][1] < m[0][0] && m[2][2] < m[0][0] {
+            let s = (V::one() + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            [
+                quarter * s,
+                (m[1][0] + m[0][1]) / s,
+                (m[2][0] + m[0][2]) / s,
+                (m[1][2] - m[2][1]) / s,
+            ]
+        } else if m[2][2] < m[1][1] {
+            let s = (V::one() + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            [
+                (m[1][0] + m[0][1]) / s,
+                quarter * s,
+                (m[2][1] + m[1][2]) / s,
+                (m[2][0] - m[0][2]) / s,
+            ]
+        } else {
+            let s = (V::one() + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            [
+                (m[2][0] + m[0][2]) / s,
+                (m[2][1] + m[1][2]) / s,
+                quarter * s,
+                (m[0][1] - m[1][0]) / s,
+            ]
+        };
+        *Quaternion::from(q).normalize()
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> From<Matrix4x4<V>> for Quaternion<V>
+where
+    V: Float,
+{
+    /// The upper-left 3x3 of the matrix must be a pure rotation.
+    fn from(m: Matrix4x4<V>) -> Self {
+        Quaternion::from(Matrix3x3::from(m))
+    }
+}
+// ============================================================================
+impl<V> From<Quaternion<V>> for Matrix3x3<V>
+where
+    V: Float,
+{
+    fn from(q: Quaternion<V>) -> Self {
+        let two = V::from(2).unwrap();
+        let (x, y, z, w) = (q[0], q[1], q[2], q[3]);
+        Matrix3x3::from([
+            Vector3::from_no_clean([
+                V::one() - two * (y * y + z * z),
+                two * (w * z + x * y),
+                two * (x * z - w * y),
+            ]),
+            Vector3::from_no_clean([
+                two * (x * y - w * z),
+                V::one() - two * (x * x + z * z),
+                two * (y * z + w * x),
+            ]),
+            Vector3::from_no_clean([
+                two * (x * z + w * y),
+                two * (y * z - w * x),
+                V::one() - two * (x * x + y * y),
+            ]),
+        ])
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> From<Quaternion<V>> for Matrix4x4<V>
+where
+    V: Float,
+{
+    fn from(q: Quaternion<V>) -> Self {
+        Matrix4x4::from(Matrix3x3::from(q))
+    }
+}
 // ============================================================================
 impl<V> Index<usize> for Quaternion<V>
 where
@@ -65,10 +172,151 @@ where
     }
 }
 // ============================================================================
+impl<V> Neg for Quaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Quaternion(self.0 * -V::one())
+    }
+}
+// ============================================================================
+impl<V> Add for Quaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Quaternion(self.0 + rhs.0)
+    }
+}
+// ============================================================================
+impl<V> Sub for Quaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Quaternion(self.0 - rhs.0)
+    }
+}
+// ============================================================================
+impl<V> Mul<V> for Quaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    fn mul(self, rhs: V) -> Self::Output {
+        Quaternion(self.0 * rhs)
+    }
+}
+// ============================================================================
+impl<V> Mul for Quaternion<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    /// Hamilton product. `a * b` rotates by `b` first, then by `a`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (ax, ay, az, aw) = (self[0], self[1], self[2], self[3]);
+        let (bx, by, bz, bw) = (rhs[0], rhs[1], rhs[2], rhs[3]);
+        Quaternion::from([
+            aw * bx + ax * bw + ay * bz - az * by,
+            aw * by - ax * bz + ay * bw + az * bx,
+            aw * bz + ax * by - ay * bx + az * bw,
+            aw * bw - ax * bx - ay * by - az * bz,
+        ])
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> MulAssign for Quaternion<V>
+where
+    V: Float,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+// ============================================================================
+impl<V> Mul<Vector3<V>> for Quaternion<V>
+where
+    V: Float,
+{
+    type Output = Vector3<V>;
+    fn mul(self, rhs: Vector3<V>) -> Self::Output {
+        self.rotate(&rhs)
+    }
+}
+// ============================================================================
 impl<V> Quaternion<V>
 where
     V: Float,
 {
+    // ========================================================================
+    /// new
+    pub fn new(x: V, y: V, z: V, w: V) -> Self {
+        Quaternion(Vector4::new(x, y, z, w))
+    }
+    // ------------------------------------------------------------------------
+    /// from_vector3
+    pub fn from_vector3(v: &Vector3<V>, w: V) -> Self {
+        Quaternion(Vector4::from_vector3(v, w))
+    }
+    // ------------------------------------------------------------------------
+    /// cast
+    ///
+    /// `None` if a component is out of the range of `U`.
+    pub fn cast<U: Float>(&self) -> Option<Quaternion<U>> {
+        self.0.cast().map(Quaternion)
+    }
+    // ========================================================================
+    /// from_axis_angle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::sif_math::{Quaternion, Vector3};
+    ///
+    /// let q = Quaternion::from_axis_angle(
+    ///     &Vector3::new(0.0f32, 0.0, 2.0),
+    ///     ::core::f32::consts::PI,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(q, Quaternion::from([0.0f32, 0.0, 1.0, 0.0]));
+    /// ```
+    pub fn from_axis_angle(axis: &Vector3<V>, angle: V) -> Result<Self> {
+        let l = axis.length2();
+        if l < V::epsilon() {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_math::quaternion::from_axis_angle",
+            )));
+        }
+        let (sin, cos) = (angle / V::from(2).unwrap()).sin_cos();
+        let s = sin / l.sqrt();
+        Ok(Quaternion::from([
+            axis[0] * s,
+            axis[1] * s,
+            axis[2] * s,
+            cos,
+        ]))
+    }
+    // ------------------------------------------------------------------------
+    /// to_axis_angle
+    ///
+    /// Returns the unit axis and the angle in `[0, 2 * PI]`.
+    /// The identity yields the x axis.
+    pub fn to_axis_angle(&self) -> (Vector3<V>, V) {
+        let q = *self.clone().normalize();
+        let w = q[3].max(-V::one()).min(V::one());
+        let angle = V::from(2).unwrap() * w.acos();
+        let s = (V::one() - w * w).sqrt();
+        if s < V::epsilon().sqrt() {
+            (Vector3::new(V::one(), V::zero(), V::zero()), angle)
+        } else {
+            (Vector3::new(q[0] / s, q[1] / s, q[2] / s), angle)
+        }
+    }
     // ========================================================================
     /// as_ptr
     pub fn as_ptr(&self) -> *const V {
@@ -80,9 +328,205 @@ where
         self.0.as_mut_ptr()
     }
     // ========================================================================
+    /// as_vector4
+    pub fn as_vector4(&self) -> &Vector4<V> {
+        &self.0
+    }
+    // ------------------------------------------------------------------------
+    /// vector
+    ///
+    /// The imaginary part `[x, y, z]`.
+    pub fn vector(&self) -> Vector3<V> {
+        Vector3::from(self.0)
+    }
+    // ------------------------------------------------------------------------
+    /// scalar
+    ///
+    /// The real part `w`.
+    pub fn scalar(&self) -> V {
+        self.0[3]
+    }
+    // ========================================================================
     /// cleanup
     pub fn cleanup(&mut self) -> &mut Self {
         let _ = self.0.cleanup();
         self
     }
+    // ========================================================================
+    /// dot
+    pub fn dot(&self, rhs: &Self) -> V {
+        self.0.dot(&rhs.0)
+    }
+    // ------------------------------------------------------------------------
+    /// length2
+    pub fn length2(&self) -> V {
+        self.0.length2()
+    }
+    // ------------------------------------------------------------------------
+    /// length
+    pub fn length(&self) -> V {
+        self.0.length()
+    }
+    // ------------------------------------------------------------------------
+    /// normalize
+    ///
+    /// A zero quaternion becomes the identity.
+    pub fn normalize(&mut self) -> &mut Self {
+        if self.length() < V::epsilon().sqrt() {
+            *self = Quaternion::default();
+        } else {
+            let _ = self.0.normalize();
+        }
+        self
+    }
+    // ========================================================================
+    /// conjugate
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(-self[0], -self[1], -self[2], self[3])
+    }
+    // ------------------------------------------------------------------------
+    /// new_inverse
+    pub fn new_inverse(&self) -> Result<Self> {
+        let l = self.length2();
+        if l < V::epsilon() {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_math::quaternion::new_inverse",
+            )));
+        }
+        Ok(Quaternion(self.conjugate().0 / l))
+    }
+    // ========================================================================
+    /// exp
+    pub fn exp(&self) -> Self {
+        let u = self.vector();
+        let theta = u.length();
+        let e = self.scalar().exp();
+        if theta < V::epsilon() {
+            Quaternion::from_vector3(&u, V::one()) * e
+        } else {
+            let (sin, cos) = theta.sin_cos();
+            Quaternion::from_vector3(&(u * (sin / theta)), cos) * e
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// ln
+    pub fn ln(&self) -> Self {
+        let u = self.vector();
+        let l = self.length();
+        let s = u.length();
+        if s < V::epsilon() {
+            Quaternion::from_vector3(&u, l.ln())
+        } else {
+            let theta = s.atan2(self.scalar());
+            Quaternion::from_vector3(&(u * (theta / s)), l.ln())
+        }
+    }
+    // ========================================================================
+    /// rotate
+    ///
+    /// Rotates `v` by this quaternion, which must be a unit quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::sif_math::{Quaternion, Vector3};
+    ///
+    /// let q = Quaternion::from_axis_angle(
+    ///     &Vector3::new(0.0f32, 0.0, 1.0),
+    ///     ::core::f32::consts::FRAC_PI_2,
+    /// )
+    /// .unwrap();
+    /// let v = q.rotate(&Vector3::new(1.0f32, 0.0, 0.0));
+    /// assert!((v - Vector3::new(0.0, 1.0, 0.0)).length() < 1.0e-6);
+    /// ```
+    pub fn rotate(&self, v: &Vector3<V>) -> Vector3<V> {
+        let u = self.vector();
+        let t = u.cross(v) * V::from(2).unwrap();
+        *v + t * self.scalar() + u.cross(&t)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::assert_approx_eq;
+    // ========================================================================
+    #[test]
+    fn cast() {
+        let q = Quaternion::new(0.0f64, 0.6, 0.0, 0.8);
+        let r = q.cast::<f32>().unwrap();
+        assert_eq!(r, Quaternion::new(0.0f32, 0.6, 0.0, 0.8));
+        assert_approx_eq!(Quaternion::<f64>::from(r), q, epsilon = 1.0e-7);
+        assert!(Quaternion::new(0.0f64, 0.0, 1.0e300, 1.0)
+            .cast::<f32>()
+            .is_none());
+    }
+    // ========================================================================
+    #[test]
+    fn mul() {
+        let a =
+            Quaternion::from_axis_angle(&Vector3::new(1.0f32, 2.0, 3.0), 0.7)
+                .unwrap();
+        let b = Quaternion::from_axis_angle(
+            &Vector3::new(-2.0f32, 0.5, 1.0),
+            -1.3,
+        )
+        .unwrap();
+        assert_approx_eq!(
+            Matrix4x4::from(a * b),
+            Matrix4x4::from(a) * Matrix4x4::from(b),
+            epsilon = 1.0e-5
+        );
+    }
+    // ========================================================================
+    #[test]
+    fn inverse() {
+        let q = Quaternion::new(1.0f32, 2.0, 3.0, 4.0);
+        let r = q * q.new_inverse().unwrap();
+        assert!((r - Quaternion::default()).length() < 1.0e-6);
+        assert!(Quaternion::new(0.0f32, 0.0, 0.0, 0.0)
+            .new_inverse()
+            .is_err());
+    }
+    // ========================================================================
+    #[test]
+    fn axis_angle() {
+        let q =
+            Quaternion::from_axis_angle(&Vector3::new(0.0f32, 3.0, 4.0), 1.2)
+                .unwrap();
+        let (axis, angle) = q.to_axis_angle();
+        assert!((axis - Vector3::new(0.0, 0.6, 0.8)).length() < 1.0e-6);
+        assert!((angle - 1.2).abs() < 1.0e-6);
+    }
+    // ========================================================================
+    #[test]
+    fn matrix() {
+        for &(x, y, z, a) in &[
+            (1.0f32, 0.0, 0.0, 3.1),
+            (0.0, 1.0, 0.0, -3.1),
+            (0.0, 0.0, 1.0, 3.0),
+            (1.0, 1.0, 1.0, 0.5),
+        ] {
+            let q = Quaternion::from_axis_angle(&Vector3::new(x, y, z), a)
+                .unwrap();
+            let p = Quaternion::from(Matrix4x4::from(q));
+            assert!((1.0 - q.dot(&p).abs()) < 1.0e-6);
+            let v = Vector3::new(0.3f32, -1.0, 2.0);
+            let m = Matrix4x4::from(q) * Vector4::from_vector3(&v, 0.0);
+            assert!((Vector3::from(m) - q * v).length() < 1.0e-5);
+        }
+    }
+    // ========================================================================
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let q = Quaternion::new(0.0f32, 0.6, 0.0, 0.8);
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, "[0.0,0.6,0.0,0.8]");
+        assert_eq!(q, serde_json::from_str::<Quaternion<f32>>(&json).unwrap());
+        let bin = bincode::serialize(&q).unwrap();
+        assert_eq!(q, bincode::deserialize::<Quaternion<f32>>(&bin).unwrap());
+    }
 }
@@ -0,0 +1,263 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! rect.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+use super::{Float, IVector2, Integer, Vector2};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// rect_define!
+///
+/// A rectangle is half-open, `[x, x + width) x [y, y + height)`, and empty
+/// when its width or height is not positive.
+macro_rules! rect_define {
+    ($name:ident, $vector:ident, $t:ident: $bound:ident) => {
+        // ////////////////////////////////////////////////////////////////////
+        // ====================================================================
+        /// struct $name
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize)
+        )]
+        pub struct $name<$t: $bound> {
+            /// position
+            pub position: $vector<$t>,
+            /// size
+            pub size: $vector<$t>,
+        }
+        // ====================================================================
+        impl<$t> $name<$t>
+        where
+            $t: $bound,
+        {
+            // ================================================================
+            /// new
+            pub fn new(x: $t, y: $t, width: $t, height: $t) -> Self {
+                $name {
+                    position: $vector::new(x, y),
+                    size: $vector::new(width, height),
+                }
+            }
+            // ----------------------------------------------------------------
+            /// from_min_max
+            pub fn from_min_max(min: &$vector<$t>, max: &$vector<$t>) -> Self {
+                $name::new(min[0], min[1], max[0] - min[0], max[1] - min[1])
+            }
+            // ----------------------------------------------------------------
+            /// cast
+            ///
+            /// `None` if a component is out of the range of `U`.
+            pub fn cast<U: $bound>(&self) -> Option<$name<U>> {
+                Some($name {
+                    position: self.position.cast()?,
+                    size: self.size.cast()?,
+                })
+            }
+            // ================================================================
+            /// x
+            pub fn x(&self) -> $t {
+                self.position[0]
+            }
+            // ----------------------------------------------------------------
+            /// y
+            pub fn y(&self) -> $t {
+                self.position[1]
+            }
+            // ----------------------------------------------------------------
+            /// width
+            pub fn width(&self) -> $t {
+                self.size[0]
+            }
+            // ----------------------------------------------------------------
+            /// height
+            pub fn height(&self) -> $t {
+                self.size[1]
+            }
+            // ================================================================
+            /// min
+            pub fn min(&self) -> $vector<$t> {
+                self.position
+            }
+            // ----------------------------------------------------------------
+            /// max
+            ///
+            /// Exclusive.
+            pub fn max(&self) -> $vector<$t> {
+                $vector::new(
+                    self.position[0] + self.size[0],
+                    self.position[1] + self.size[1],
+                )
+            }
+            // ================================================================
+            /// is_empty
+            pub fn is_empty(&self) -> bool {
+                !($t::zero() < self.size[0] && $t::zero() < self.size[1])
+            }
+            // ----------------------------------------------------------------
+            /// contains_point
+            pub fn contains_point(&self, p: &$vector<$t>) -> bool {
+                let max = self.max();
+                self.position[0] <= p[0]
+                    && p[0] < max[0]
+                    && self.position[1] <= p[1]
+                    && p[1] < max[1]
+            }
+            // ----------------------------------------------------------------
+            /// contains
+            ///
+            /// An empty `rhs` is contained in every rectangle.
+            pub fn contains(&self, rhs: &Self) -> bool {
+                if rhs.is_empty() {
+                    return true;
+                }
+                let (max, rhs_max) = (self.max(), rhs.max());
+                self.position[0] <= rhs.position[0]
+                    && self.position[1] <= rhs.position[1]
+                    && rhs_max[0] <= max[0]
+                    && rhs_max[1] <= max[1]
+            }
+            // ================================================================
+            /// intersects
+            pub fn intersects(&self, rhs: &Self) -> bool {
+                self.intersection(rhs).is_some()
+            }
+            // ----------------------------------------------------------------
+            /// intersection
+            ///
+            /// `None` when the rectangles do not overlap.
+            pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+                let (max, rhs_max) = (self.max(), rhs.max());
+                let r = $name::from_min_max(
+                    &$vector::new(
+                        self.position[0].max(rhs.position[0]),
+                        self.position[1].max(rhs.position[1]),
+                    ),
+                    &$vector::new(
+                        max[0].min(rhs_max[0]),
+                        max[1].min(rhs_max[1]),
+                    ),
+                );
+                if r.is_empty() {
+                    None
+                } else {
+                    Some(r)
+                }
+            }
+            // ----------------------------------------------------------------
+            /// union
+            ///
+            /// The smallest rectangle containing both. Empty rectangles are
+            /// ignored.
+            pub fn union(&self, rhs: &Self) -> Self {
+                if rhs.is_empty() {
+                    return *self;
+                }
+                if self.is_empty() {
+                    return *rhs;
+                }
+                let (max, rhs_max) = (self.max(), rhs.max());
+                $name::from_min_max(
+                    &$vector::new(
+                        self.position[0].min(rhs.position[0]),
+                        self.position[1].min(rhs.position[1]),
+                    ),
+                    &$vector::new(
+                        max[0].max(rhs_max[0]),
+                        max[1].max(rhs_max[1]),
+                    ),
+                )
+            }
+        }
+    };
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+rect_define!(Rect, Vector2, V: Float);
+// ----------------------------------------------------------------------------
+impl From<Rect<f32>> for Rect<f64> {
+    fn from(src: Rect<f32>) -> Self {
+        Rect {
+            position: src.position.into(),
+            size: src.size.into(),
+        }
+    }
+}
+// ============================================================================
+rect_define!(IRect, IVector2, I: Integer);
+// ----------------------------------------------------------------------------
+/// irect_widen!
+///
+/// The lossless `From` between the components, as `IVector2` has.
+macro_rules! irect_widen {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl From<IRect<$from>> for IRect<$to> {
+                fn from(src: IRect<$from>) -> Self {
+                    IRect {
+                        position: src.position.into(),
+                        size: src.size.into(),
+                    }
+                }
+            }
+        )*
+    };
+}
+irect_widen!(i8 => i16, i16 => i32, i32 => i64);
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    // ========================================================================
+    #[test]
+    fn irect() {
+        let a = IRect::new(0i32, 0, 10, 10);
+        let b = IRect::new(5i32, -5, 10, 10);
+        assert_eq!(a.intersection(&b), Some(IRect::new(5, 0, 5, 5)));
+        assert_eq!(a.union(&b), IRect::new(0, -5, 15, 15));
+        assert!(a.intersects(&b));
+        assert_eq!(a.intersection(&IRect::new(10, 0, 5, 5)), None);
+        assert!(a.contains(&IRect::new(2, 2, 8, 8)));
+        assert!(!a.contains(&b));
+        assert!(a.contains_point(&IVector2::new(9, 0)));
+        assert!(!a.contains_point(&IVector2::new(10, 0)));
+        assert_eq!(a.union(&IRect::default()), a);
+    }
+    // ========================================================================
+    #[test]
+    fn cast() {
+        let a = IRect::new(-3i32, 4, 100, 200);
+        assert_eq!(a.cast::<i8>(), None);
+        assert_eq!(a.cast::<i16>(), Some(IRect::new(-3i16, 4, 100, 200)));
+        assert_eq!(IRect::<i64>::from(a), IRect::new(-3i64, 4, 100, 200));
+        let b = IRect::new(-3i8, 4, 100, 127);
+        assert_eq!(
+            IRect::<i32>::from(IRect::<i16>::from(b)),
+            IRect::new(-3, 4, 100, 127)
+        );
+
+        let r = Rect::new(0.5f64, -1.0, 2.0, 1.0e300);
+        assert!(r.cast::<f32>().is_none());
+        let r = Rect::new(0.5f32, -1.0, 2.0, 3.0);
+        assert_eq!(r.cast::<f64>(), Some(Rect::new(0.5f64, -1.0, 2.0, 3.0)));
+        assert_eq!(Rect::<f64>::from(r), Rect::new(0.5, -1.0, 2.0, 3.0));
+    }
+    // ========================================================================
+    #[test]
+    fn rect() {
+        let a = Rect::new(0.0f32, 0.0, 1.0, 2.0);
+        let b = Rect::new(0.5f32, 1.5, 1.0, 1.0);
+        assert_eq!(a.intersection(&b), Some(Rect::new(0.5, 1.5, 0.5, 0.5)));
+        assert_eq!(a.union(&b), Rect::new(0.0, 0.0, 1.5, 2.5));
+        assert!(a.contains_point(&Vector2::new(0.5, 1.99)));
+    }
+}
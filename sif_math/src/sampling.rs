@@ -0,0 +1,264 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! sampling.rs
+//!
+//! Each `sample_*` maps a uniform `u` in `[0, 1)^2` onto a distribution.
+//! `u` may come from `rand`, e.g. `Vector2::from(::rand::random::<[f32;
+//! 2]>())`, or from the low-discrepancy `halton*` and `sobol2`.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use super::{Float, Vector2, Vector3};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// two_pi
+fn two_pi<V: Float>() -> V {
+    V::from(2.0 * ::core::f64::consts::PI).unwrap()
+}
+// ----------------------------------------------------------------------------
+/// unit
+///
+/// `x / 2^32`, kept below `1`.
+fn unit<V: Float>(x: u32) -> V {
+    (V::from(x).unwrap() / V::from(4_294_967_296u64).unwrap())
+        .min(V::one() - V::epsilon())
+}
+// ============================================================================
+/// sample_sphere
+///
+/// Uniform on the unit sphere.
+pub fn sample_sphere<V: Float>(u: &Vector2<V>) -> Vector3<V> {
+    let z = V::one() - V::from(2).unwrap() * u[0];
+    let r = (V::one() - z * z).max(V::zero()).sqrt();
+    let (s, c) = (two_pi::<V>() * u[1]).sin_cos();
+    Vector3::new(r * c, r * s, z)
+}
+// ----------------------------------------------------------------------------
+/// sample_hemisphere
+///
+/// Uniform on the unit hemisphere around `+Z`.
+pub fn sample_hemisphere<V: Float>(u: &Vector2<V>) -> Vector3<V> {
+    let z = u[0];
+    let r = (V::one() - z * z).max(V::zero()).sqrt();
+    let (s, c) = (two_pi::<V>() * u[1]).sin_cos();
+    Vector3::new(r * c, r * s, z)
+}
+// ----------------------------------------------------------------------------
+/// sample_hemisphere_cosine
+///
+/// Cosine weighted on the unit hemisphere around `+Z`, the density is
+/// `z / π`.
+pub fn sample_hemisphere_cosine<V: Float>(u: &Vector2<V>) -> Vector3<V> {
+    let d = sample_disk(u);
+    let z = (V::one() - d[0] * d[0] - d[1] * d[1]).max(V::zero()).sqrt();
+    Vector3::new(d[0], d[1], z)
+}
+// ----------------------------------------------------------------------------
+/// sample_disk
+///
+/// Uniform on the unit disk, by the concentric mapping which keeps the
+/// strata of `u`.
+pub fn sample_disk<V: Float>(u: &Vector2<V>) -> Vector2<V> {
+    let two = V::from(2).unwrap();
+    let (a, b) = (two * u[0] - V::one(), two * u[1] - V::one());
+    if a == V::zero() && b == V::zero() {
+        return Vector2::new(V::zero(), V::zero());
+    }
+    let quarter = V::from(::core::f64::consts::FRAC_PI_4).unwrap();
+    let (r, theta) = if a.abs() > b.abs() {
+        (a, quarter * (b / a))
+    } else {
+        (b, quarter + quarter - quarter * (a / b))
+    };
+    let (s, c) = theta.sin_cos();
+    Vector2::new(r * c, r * s)
+}
+// ----------------------------------------------------------------------------
+/// sample_triangle
+///
+/// Uniform on the triangle `a`, `b`, `c`.
+pub fn sample_triangle<V: Float>(
+    u: &Vector2<V>,
+    a: &Vector3<V>,
+    b: &Vector3<V>,
+    c: &Vector3<V>,
+) -> Vector3<V> {
+    let s = u[0].sqrt();
+    let (wa, wb) = (V::one() - s, u[1] * s);
+    *a * wa + *b * wb + *c * (V::one() - wa - wb)
+}
+// ----------------------------------------------------------------------------
+/// sample_cone
+///
+/// Uniform in the solid angle of the cone around `+Z`, whose half angle has
+/// the cosine `cos_max`.
+pub fn sample_cone<V: Float>(u: &Vector2<V>, cos_max: V) -> Vector3<V> {
+    let z = V::one() - u[0] + u[0] * cos_max;
+    let r = (V::one() - z * z).max(V::zero()).sqrt();
+    let (s, c) = (two_pi::<V>() * u[1]).sin_cos();
+    Vector3::new(r * c, r * s, z)
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// radical_inverse
+///
+/// The `index`-th element of the van der Corput sequence in `base`.
+pub fn radical_inverse<V: Float>(index: u32, base: u32) -> V {
+    let inv = V::one() / V::from(base).unwrap();
+    let (mut i, mut f, mut r) = (index, inv, V::zero());
+    while i > 0 {
+        r += V::from(i % base).unwrap() * f;
+        f *= inv;
+        i /= base;
+    }
+    r.min(V::one() - V::epsilon())
+}
+// ----------------------------------------------------------------------------
+/// halton2
+///
+/// The Halton sequence in the bases `2` and `3`.
+pub fn halton2<V: Float>(index: u32) -> Vector2<V> {
+    Vector2::new(radical_inverse(index, 2), radical_inverse(index, 3))
+}
+// ----------------------------------------------------------------------------
+/// halton3
+///
+/// The Halton sequence in the bases `2`, `3` and `5`.
+pub fn halton3<V: Float>(index: u32) -> Vector3<V> {
+    Vector3::new(
+        radical_inverse(index, 2),
+        radical_inverse(index, 3),
+        radical_inverse(index, 5),
+    )
+}
+// ----------------------------------------------------------------------------
+/// sobol2
+///
+/// The first two dimensions of the Sobol sequence, XORed by `scramble`.
+/// A random `scramble` for each pixel decorrelates the sets while keeping
+/// their stratification.
+///
+/// # Examples
+///
+/// ```
+/// use ::sif_math::{sobol2, Vector2};
+///
+/// assert_eq!(sobol2::<f32>(0, [0, 0]), Vector2::new(0.0, 0.0));
+/// assert_eq!(sobol2::<f32>(1, [0, 0]), Vector2::new(0.5, 0.5));
+/// assert_eq!(sobol2::<f32>(2, [0, 0]), Vector2::new(0.25, 0.75));
+/// ```
+pub fn sobol2<V: Float>(index: u32, scramble: [u32; 2]) -> Vector2<V> {
+    let (mut x, mut y) = (scramble[0], scramble[1]);
+    let (mut i, mut v) = (index, 1u32 << 31);
+    let mut bit = 0;
+    while i > 0 {
+        if i & 1 != 0 {
+            x ^= 1u32 << (31 - bit);
+            y ^= v;
+        }
+        i >>= 1;
+        v ^= v >> 1;
+        bit += 1;
+    }
+    Vector2::new(unit(x), unit(y))
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    // ========================================================================
+    const N: u32 = 4096;
+    // ------------------------------------------------------------------------
+    fn mean<T>(f: impl Fn(&Vector2<f64>) -> T, g: impl Fn(&T) -> f64) -> f64 {
+        (0..N).map(|i| g(&f(&halton2(i)))).sum::<f64>() / f64::from(N)
+    }
+    // ========================================================================
+    #[test]
+    fn sequence() {
+        assert_eq!(radical_inverse::<f64>(1, 2), 0.5);
+        assert_eq!(radical_inverse::<f64>(6, 2), 0.375);
+        assert_eq!(radical_inverse::<f64>(2, 3), 2.0 / 3.0);
+        assert_eq!(halton3::<f64>(1), Vector3::new(0.5, 1.0 / 3.0, 0.2));
+        assert_eq!(sobol2::<f64>(3, [0, 0]), Vector2::new(0.75, 0.25));
+        // every 2^k points hit each of the 2^k strata of either axis
+        let mut x = [false; 16];
+        let mut y = [false; 16];
+        for i in 0..16 {
+            let p = sobol2::<f64>(i, [0x1234_5678, 0x9abc_def0]);
+            x[(p[0] * 16.0) as usize] = true;
+            y[(p[1] * 16.0) as usize] = true;
+        }
+        assert!(x.iter().chain(y.iter()).all(|b| *b));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn sphere() {
+        for i in 0..N {
+            let v = sample_sphere(&halton2::<f64>(i));
+            assert!((v.length() - 1.0).abs() < 1.0e-12);
+            let h = sample_hemisphere(&halton2::<f64>(i));
+            assert!((h.length() - 1.0).abs() < 1.0e-12 && h[2] >= 0.0);
+        }
+        for k in 0..3 {
+            assert!(mean(sample_sphere, |v| v[k]).abs() < 1.0e-2);
+        }
+        assert!((mean(sample_hemisphere, |v| v[2]) - 0.5).abs() < 1.0e-2);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn hemisphere_cosine() {
+        for i in 0..N {
+            let v = sample_hemisphere_cosine(&halton2::<f64>(i));
+            assert!((v.length() - 1.0).abs() < 1.0e-12 && v[2] >= 0.0);
+        }
+        // E[z] = 2 / 3 for the density z / π
+        let z = mean(sample_hemisphere_cosine, |v| v[2]);
+        assert!((z - 2.0 / 3.0).abs() < 1.0e-2);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn disk() {
+        for i in 0..N {
+            assert!(sample_disk(&halton2::<f64>(i)).length() <= 1.0);
+        }
+        // E[r^2] = 1 / 2
+        let r2 = mean(sample_disk, |v| v.length2());
+        assert!((r2 - 0.5).abs() < 1.0e-2);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn triangle() {
+        let a = Vector3::new(0.0, 0.0, 1.0);
+        let b = Vector3::new(3.0, 0.0, 1.0);
+        let c = Vector3::new(0.0, 6.0, 1.0);
+        for i in 0..N {
+            let p = sample_triangle(&halton2::<f64>(i), &a, &b, &c);
+            assert!(p[0] >= 0.0 && p[1] >= 0.0);
+            assert!(p[0] / 3.0 + p[1] / 6.0 <= 1.0 + 1.0e-12);
+        }
+        // the centroid
+        let f = |u: &Vector2<f64>| sample_triangle(u, &a, &b, &c);
+        assert!((mean(f, |v| v[0]) - 1.0).abs() < 1.0e-2);
+        assert!((mean(f, |v| v[1]) - 2.0).abs() < 1.0e-2);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn cone() {
+        let cos_max = 0.8;
+        for i in 0..N {
+            let v = sample_cone(&halton2::<f64>(i), cos_max);
+            assert!((v.length() - 1.0).abs() < 1.0e-12);
+            assert!(v[2] >= cos_max - 1.0e-12);
+        }
+        let z = mean(|u| sample_cone(u, cos_max), |v| v[2]);
+        assert!((z - 0.9).abs() < 1.0e-2);
+    }
+}
@@ -0,0 +1,222 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! simd.rs
+//!
+//! The `f32` path of `Matrix4x4 * Matrix4x4`, `Matrix4x4 * Vector4` and of
+//! `Vector4` add, sub, scale and dot. The code is written lane by lane on
+//! `[f32; 4]`, which the compiler turns into SSE2 / NEON instructions, and it
+//! skips `cleanup`. `f32` is found by size and precision, so `Float` needs
+//! no `'static` bound.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use super::{Float, Matrix4x4, Vector4};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// type F32x4
+type F32x4 = [f32; 4];
+// ============================================================================
+/// is_f32
+///
+/// `V` is `f32`, or a float of the same size and precision whose values
+/// pass through `f32` unchanged.
+#[inline(always)]
+fn is_f32<V: Float>() -> bool {
+    ::core::mem::size_of::<V>() == 4
+        && V::epsilon().to_f32() == Some(f32::EPSILON)
+}
+// ----------------------------------------------------------------------------
+/// lanes
+///
+/// `None` unless `V` is `f32`. The conversions are no-ops for `f32`.
+#[inline(always)]
+fn lanes<V: Float>(v: &Vector4<V>) -> Option<F32x4> {
+    if !is_f32::<V>() {
+        return None;
+    }
+    Some([
+        v[0].to_f32()?,
+        v[1].to_f32()?,
+        v[2].to_f32()?,
+        v[3].to_f32()?,
+    ])
+}
+// ----------------------------------------------------------------------------
+/// columns
+#[inline(always)]
+fn columns<V: Float>(m: &Matrix4x4<V>) -> Option<[F32x4; 4]> {
+    Some([lanes(&m[0])?, lanes(&m[1])?, lanes(&m[2])?, lanes(&m[3])?])
+}
+// ----------------------------------------------------------------------------
+/// vector
+#[inline(always)]
+fn vector<V: Float>(a: F32x4) -> Option<Vector4<V>> {
+    let f = |x: f32| <V as ::num::cast::NumCast>::from(x);
+    Some(Vector4::from_no_clean([
+        f(a[0])?,
+        f(a[1])?,
+        f(a[2])?,
+        f(a[3])?,
+    ]))
+}
+// ============================================================================
+/// scale
+#[inline(always)]
+fn scale(a: F32x4, s: f32) -> F32x4 {
+    [a[0] * s, a[1] * s, a[2] * s, a[3] * s]
+}
+// ----------------------------------------------------------------------------
+/// scale_add
+#[inline(always)]
+fn scale_add(acc: F32x4, a: F32x4, s: f32) -> F32x4 {
+    [
+        acc[0] + a[0] * s,
+        acc[1] + a[1] * s,
+        acc[2] + a[2] * s,
+        acc[3] + a[3] * s,
+    ]
+}
+// ----------------------------------------------------------------------------
+/// mul_columns
+#[inline(always)]
+fn mul_columns(m: &[F32x4; 4], v: F32x4) -> F32x4 {
+    let mut acc = scale(m[0], v[0]);
+    acc = scale_add(acc, m[1], v[1]);
+    acc = scale_add(acc, m[2], v[2]);
+    scale_add(acc, m[3], v[3])
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// add
+///
+/// `None` unless `V` is `f32`.
+#[inline(always)]
+pub(crate) fn add<V: Float>(
+    lhs: &Vector4<V>,
+    rhs: &Vector4<V>,
+) -> Option<Vector4<V>> {
+    let (a, b) = (lanes(lhs)?, lanes(rhs)?);
+    vector([a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]])
+}
+// ----------------------------------------------------------------------------
+/// sub
+///
+/// `None` unless `V` is `f32`.
+#[inline(always)]
+pub(crate) fn sub<V: Float>(
+    lhs: &Vector4<V>,
+    rhs: &Vector4<V>,
+) -> Option<Vector4<V>> {
+    let (a, b) = (lanes(lhs)?, lanes(rhs)?);
+    vector([a[0] - b[0], a[1] - b[1], a[2] - b[2], a[3] - b[3]])
+}
+// ----------------------------------------------------------------------------
+/// mul_scalar
+///
+/// `None` unless `V` is `f32`.
+#[inline(always)]
+pub(crate) fn mul_scalar<V: Float>(
+    lhs: &Vector4<V>,
+    rhs: V,
+) -> Option<Vector4<V>> {
+    vector(scale(lanes(lhs)?, rhs.to_f32()?))
+}
+// ----------------------------------------------------------------------------
+/// dot
+///
+/// `None` unless `V` is `f32`.
+#[inline(always)]
+pub(crate) fn dot<V: Float>(lhs: &Vector4<V>, rhs: &Vector4<V>) -> Option<V> {
+    let (a, b) = (lanes(lhs)?, lanes(rhs)?);
+    let m = [a[0] * b[0], a[1] * b[1], a[2] * b[2], a[3] * b[3]];
+    <V as ::num::cast::NumCast>::from((m[0] + m[1]) + (m[2] + m[3]))
+}
+// ============================================================================
+/// mul_matrix4x4
+///
+/// `None` unless `V` is `f32`.
+#[inline(always)]
+pub(crate) fn mul_matrix4x4<V: Float>(
+    lhs: &Matrix4x4<V>,
+    rhs: &Matrix4x4<V>,
+) -> Option<Matrix4x4<V>> {
+    let (l, r) = (columns(lhs)?, columns(rhs)?);
+    Some(Matrix4x4::from_no_clean([
+        vector(mul_columns(&l, r[0]))?,
+        vector(mul_columns(&l, r[1]))?,
+        vector(mul_columns(&l, r[2]))?,
+        vector(mul_columns(&l, r[3]))?,
+    ]))
+}
+// ----------------------------------------------------------------------------
+/// mul_vector4
+///
+/// `None` unless `V` is `f32`.
+#[inline(always)]
+pub(crate) fn mul_vector4<V: Float>(
+    lhs: &Matrix4x4<V>,
+    rhs: &Vector4<V>,
+) -> Option<Vector4<V>> {
+    vector(mul_columns(&columns(lhs)?, lanes(rhs)?))
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    // ========================================================================
+    fn matrix<V: Float>(seed: f64) -> Matrix4x4<V> {
+        let mut m = Matrix4x4::<V>::default();
+        for i in 0..4 {
+            for j in 0..4 {
+                let x = (seed + (i * 4 + j) as f64).sin() * 3.0;
+                m[i][j] = V::from(x).unwrap();
+            }
+        }
+        m
+    }
+    // ========================================================================
+    #[test]
+    fn dispatch() {
+        let m = matrix::<f32>(0.5);
+        assert!(mul_matrix4x4(&m, &m).is_some());
+        let n = matrix::<f64>(0.5);
+        assert!(mul_matrix4x4(&n, &n).is_none());
+    }
+    // ========================================================================
+    #[test]
+    fn vector() {
+        let (a, b) = (
+            Vector4::new(0.5f32, -1.0, 2.0, 3.0),
+            Vector4::new(1.0f32, 2.0, -0.25, 0.5),
+        );
+        assert!(add(&a, &b).is_some());
+        assert!(dot(&a.cast::<f64>().unwrap(), &b.cast().unwrap()).is_none());
+        assert_eq!(a + b, Vector4::new(1.5, 1.0, 1.75, 3.5));
+        assert_eq!(a - b, Vector4::new(-0.5, -3.0, 2.25, 2.5));
+        assert_eq!(a * 2.0, Vector4::new(1.0, -2.0, 4.0, 6.0));
+        assert_eq!(a.dot(&b), 0.5 - 2.0 - 0.5 + 1.5);
+    }
+    // ========================================================================
+    #[test]
+    fn mul() {
+        let (a, b) = (matrix::<f32>(0.1), matrix::<f32>(2.3));
+        let (c, d) = (matrix::<f64>(0.1), matrix::<f64>(2.3));
+        let (r, s) = (a * b, c * d);
+        let v = Vector4::new(0.3f32, -1.2, 2.0, 1.0);
+        let (p, q) = (a * v, c * Vector4::new(0.3f64, -1.2, 2.0, 1.0));
+        for i in 0..4 {
+            assert!((f64::from(p[i]) - q[i]).abs() < 1.0e-4);
+            for j in 0..4 {
+                assert!((f64::from(r[i][j]) - s[i][j]).abs() < 1.0e-4);
+            }
+        }
+    }
+}
@@ -0,0 +1,732 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! spline.rs
+//!
+//! A spline of `n` segments is parametrized by `u` in `[0, n]`, segment `i`
+//! covering `[i, i + 1]`.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use alloc::{format, string::String, vec::Vec};
+use core::ops::{Add, Mul, Sub};
+// ----------------------------------------------------------------------------
+use super::{Error, Float, Result, Vector2, Vector3};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait SplineVector
+pub trait SplineVector<V: Float>:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<V, Output = Self>
+{
+    /// length
+    fn length(&self) -> V;
+}
+// ----------------------------------------------------------------------------
+impl<V: Float> SplineVector<V> for Vector2<V> {
+    fn length(&self) -> V {
+        Vector2::length(self)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V: Float> SplineVector<V> for Vector3<V> {
+    fn length(&self) -> V {
+        Vector3::length(self)
+    }
+}
+// ============================================================================
+/// combine
+fn combine<V, P>(p: [P; 4], w: [V; 4]) -> P
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    p[0] * w[0] + p[1] * w[1] + p[2] * w[2] + p[3] * w[3]
+}
+// ----------------------------------------------------------------------------
+/// hermite_weights
+///
+/// The weights of `[p0, m0, p1, m1]` and of their derivatives.
+fn hermite_weights<V: Float>(t: V) -> ([V; 4], [V; 4]) {
+    let (one, two, three) =
+        (V::one(), V::from(2).unwrap(), V::from(3).unwrap());
+    let (t2, t3) = (t * t, t * t * t);
+    (
+        [
+            two * t3 - three * t2 + one,
+            t3 - two * t2 + t,
+            three * t2 - two * t3,
+            t3 - t2,
+        ],
+        [
+            V::from(6).unwrap() * (t2 - t),
+            three * t2 - V::from(4).unwrap() * t + one,
+            V::from(6).unwrap() * (t - t2),
+            three * t2 - two * t,
+        ],
+    )
+}
+// ----------------------------------------------------------------------------
+/// check_points
+fn check_points<P>(points: &[P], min: usize, name: &str) -> Result<()> {
+    if points.len() < min {
+        Err(Error::InvalidArgument(format!(
+            "::sif_math::spline::{}: needs {} points",
+            name, min
+        )))
+    } else {
+        Ok(())
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait Spline
+pub trait Spline<V: Float> {
+    /// type Vector
+    type Vector: SplineVector<V>;
+    // ========================================================================
+    /// segments
+    fn segments(&self) -> usize;
+    // ------------------------------------------------------------------------
+    /// segment_point
+    ///
+    /// `t` in `[0, 1]` along segment `i`.
+    fn segment_point(&self, i: usize, t: V) -> Self::Vector;
+    // ------------------------------------------------------------------------
+    /// segment_tangent
+    ///
+    /// The derivative by `t` along segment `i`.
+    fn segment_tangent(&self, i: usize, t: V) -> Self::Vector;
+    // ========================================================================
+    /// locate
+    ///
+    /// Splits `u` into a segment and a local parameter, clamping it to
+    /// `[0, segments]`.
+    fn locate(&self, u: V) -> (usize, V) {
+        let n = self.segments();
+        let u = u.max(V::zero());
+        let i = u.floor().to_usize().unwrap_or(0);
+        if n <= i {
+            (n - 1, V::one())
+        } else {
+            (i, u - V::from(i).unwrap())
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// point
+    fn point(&self, u: V) -> Self::Vector {
+        let (i, t) = self.locate(u);
+        self.segment_point(i, t)
+    }
+    // ------------------------------------------------------------------------
+    /// tangent
+    fn tangent(&self, u: V) -> Self::Vector {
+        let (i, t) = self.locate(u);
+        self.segment_tangent(i, t)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// enum CatmullRomType
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CatmullRomType {
+    /// Uniform, `alpha = 0`
+    Uniform,
+    /// Centripetal, `alpha = 1 / 2`, without cusps or self-intersections
+    Centripetal,
+    /// Chordal, `alpha = 1`
+    Chordal,
+}
+// ============================================================================
+/// struct CatmullRom
+///
+/// Passes through every point. The ends are extended by reflecting the
+/// neighbouring point.
+#[derive(Debug, Clone)]
+pub struct CatmullRom<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    /// points
+    points: Vec<P>,
+    /// alpha
+    alpha: V,
+}
+// ============================================================================
+impl<V, P> CatmullRom<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    // ========================================================================
+    /// new
+    pub fn new(points: Vec<P>, type_: CatmullRomType) -> Result<Self> {
+        check_points(&points, 2, "CatmullRom::new")?;
+        let alpha = match type_ {
+            CatmullRomType::Uniform => V::zero(),
+            CatmullRomType::Centripetal => V::from(0.5).unwrap(),
+            CatmullRomType::Chordal => V::one(),
+        };
+        Ok(CatmullRom { points, alpha })
+    }
+    // ========================================================================
+    /// points
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    // ========================================================================
+    /// control
+    fn control(&self, i: usize) -> [P; 4] {
+        let p = &self.points;
+        let n = p.len();
+        let p1 = p[i];
+        let p2 = p[i + 1];
+        let p0 = if i == 0 { p1 + (p1 - p2) } else { p[i - 1] };
+        let p3 = if i + 2 < n { p[i + 2] } else { p2 + (p2 - p1) };
+        [p0, p1, p2, p3]
+    }
+    // ------------------------------------------------------------------------
+    /// hermite
+    ///
+    /// The end points and tangents of segment `i`.
+    fn hermite(&self, i: usize) -> [P; 4] {
+        let [p0, p1, p2, p3] = self.control(i);
+        let knot = |a: P, b: P| {
+            let d = (b - a).length().powf(self.alpha);
+            if d < V::epsilon() {
+                V::one()
+            } else {
+                d
+            }
+        };
+        let (t01, t12, t23) = (knot(p0, p1), knot(p1, p2), knot(p2, p3));
+        let m1 = ((p1 - p0) * (V::one() / t01)
+            - (p2 - p0) * (V::one() / (t01 + t12))
+            + (p2 - p1) * (V::one() / t12))
+            * t12;
+        let m2 = ((p2 - p1) * (V::one() / t12)
+            - (p3 - p1) * (V::one() / (t12 + t23))
+            + (p3 - p2) * (V::one() / t23))
+            * t12;
+        [p1, m1, p2, m2]
+    }
+}
+// ============================================================================
+impl<V, P> Spline<V> for CatmullRom<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    type Vector = P;
+    // ========================================================================
+    fn segments(&self) -> usize {
+        self.points.len() - 1
+    }
+    // ------------------------------------------------------------------------
+    fn segment_point(&self, i: usize, t: V) -> P {
+        combine(self.hermite(i), hermite_weights(t).0)
+    }
+    // ------------------------------------------------------------------------
+    fn segment_tangent(&self, i: usize, t: V) -> P {
+        combine(self.hermite(i), hermite_weights(t).1)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Hermite
+///
+/// Cubic Hermite spline through `points` with the derivatives `tangents`.
+#[derive(Debug, Clone)]
+pub struct Hermite<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    /// points
+    points: Vec<P>,
+    /// tangents
+    tangents: Vec<P>,
+    /// phantom
+    phantom: ::core::marker::PhantomData<V>,
+}
+// ============================================================================
+impl<V, P> Hermite<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    // ========================================================================
+    /// new
+    pub fn new(points: Vec<P>, tangents: Vec<P>) -> Result<Self> {
+        check_points(&points, 2, "Hermite::new")?;
+        if points.len() != tangents.len() {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_math::spline::Hermite::new: tangents",
+            )));
+        }
+        Ok(Hermite {
+            points,
+            tangents,
+            phantom: ::core::marker::PhantomData,
+        })
+    }
+    // ========================================================================
+    /// points
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    // ------------------------------------------------------------------------
+    /// tangents
+    pub fn tangents(&self) -> &[P] {
+        &self.tangents
+    }
+    // ========================================================================
+    /// control
+    fn control(&self, i: usize) -> [P; 4] {
+        [
+            self.points[i],
+            self.tangents[i],
+            self.points[i + 1],
+            self.tangents[i + 1],
+        ]
+    }
+}
+// ============================================================================
+impl<V, P> Spline<V> for Hermite<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    type Vector = P;
+    // ========================================================================
+    fn segments(&self) -> usize {
+        self.points.len() - 1
+    }
+    // ------------------------------------------------------------------------
+    fn segment_point(&self, i: usize, t: V) -> P {
+        combine(self.control(i), hermite_weights(t).0)
+    }
+    // ------------------------------------------------------------------------
+    fn segment_tangent(&self, i: usize, t: V) -> P {
+        combine(self.control(i), hermite_weights(t).1)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct BSpline
+///
+/// Uniform cubic B-spline. It approximates the points, and is `C2`.
+#[derive(Debug, Clone)]
+pub struct BSpline<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    /// points
+    points: Vec<P>,
+    /// phantom
+    phantom: ::core::marker::PhantomData<V>,
+}
+// ============================================================================
+impl<V, P> BSpline<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    // ========================================================================
+    /// new
+    pub fn new(points: Vec<P>) -> Result<Self> {
+        check_points(&points, 4, "BSpline::new")?;
+        Ok(BSpline {
+            points,
+            phantom: ::core::marker::PhantomData,
+        })
+    }
+    // ========================================================================
+    /// points
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    // ========================================================================
+    /// control
+    fn control(&self, i: usize) -> [P; 4] {
+        let p = &self.points;
+        [p[i], p[i + 1], p[i + 2], p[i + 3]]
+    }
+}
+// ============================================================================
+impl<V, P> Spline<V> for BSpline<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    type Vector = P;
+    // ========================================================================
+    fn segments(&self) -> usize {
+        self.points.len() - 3
+    }
+    // ------------------------------------------------------------------------
+    fn segment_point(&self, i: usize, t: V) -> P {
+        let (one, three) = (V::one(), V::from(3).unwrap());
+        let s = one / V::from(6).unwrap();
+        let (it, t2, t3) = (one - t, t * t, t * t * t);
+        combine(
+            self.control(i),
+            [
+                it * it * it * s,
+                (three * t3 - V::from(6).unwrap() * t2 + V::from(4).unwrap())
+                    * s,
+                (three * (t + t2 - t3) + one) * s,
+                t3 * s,
+            ],
+        )
+    }
+    // ------------------------------------------------------------------------
+    fn segment_tangent(&self, i: usize, t: V) -> P {
+        let (one, two) = (V::one(), V::from(2).unwrap());
+        let half = one / two;
+        let (it, t2) = (one - t, t * t);
+        combine(
+            self.control(i),
+            [
+                -(it * it) * half,
+                (V::from(3).unwrap() * t2 - V::from(4).unwrap() * t) * half,
+                (one + two * t - V::from(3).unwrap() * t2) * half,
+                t2 * half,
+            ],
+        )
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct BezierPath
+///
+/// Cubic Bezier segments sharing their end points, `3 * n + 1` points for
+/// `n` segments.
+#[derive(Debug, Clone)]
+pub struct BezierPath<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    /// points
+    points: Vec<P>,
+    /// phantom
+    phantom: ::core::marker::PhantomData<V>,
+}
+// ============================================================================
+impl<V, P> BezierPath<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    // ========================================================================
+    /// new
+    pub fn new(points: Vec<P>) -> Result<Self> {
+        check_points(&points, 4, "BezierPath::new")?;
+        if points.len() % 3 != 1 {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_math::spline::BezierPath::new: needs 3 * n + 1 points",
+            )));
+        }
+        Ok(BezierPath {
+            points,
+            phantom: ::core::marker::PhantomData,
+        })
+    }
+    // ========================================================================
+    /// points
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    // ========================================================================
+    /// control
+    fn control(&self, i: usize) -> [P; 4] {
+        let p = &self.points[i * 3..];
+        [p[0], p[1], p[2], p[3]]
+    }
+}
+// ============================================================================
+impl<V, P> Spline<V> for BezierPath<V, P>
+where
+    V: Float,
+    P: SplineVector<V>,
+{
+    type Vector = P;
+    // ========================================================================
+    fn segments(&self) -> usize {
+        self.points.len() / 3
+    }
+    // ------------------------------------------------------------------------
+    fn segment_point(&self, i: usize, t: V) -> P {
+        let three = V::from(3).unwrap();
+        let it = V::one() - t;
+        combine(
+            self.control(i),
+            [
+                it * it * it,
+                three * it * it * t,
+                three * it * t * t,
+                t * t * t,
+            ],
+        )
+    }
+    // ------------------------------------------------------------------------
+    fn segment_tangent(&self, i: usize, t: V) -> P {
+        let three = V::from(3).unwrap();
+        let six = V::from(6).unwrap();
+        let it = V::one() - t;
+        combine(
+            self.control(i),
+            [
+                -three * it * it,
+                three * it * it - six * it * t,
+                six * it * t - three * t * t,
+                three * t * t,
+            ],
+        )
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct ArcLengthTable
+///
+/// Maps the distance along a spline to its parameter, by sampling each
+/// segment as a polyline.
+///
+/// # Examples
+///
+/// ```
+/// use ::sif_math::{
+///     ArcLengthTable, CatmullRom, CatmullRomType, Spline, Vector2,
+/// };
+///
+/// let spline = CatmullRom::new(
+///     vec![Vector2::new(0.0f32, 0.0), Vector2::new(1.0, 0.0),
+///          Vector2::new(3.0, 0.0)],
+///     CatmullRomType::Centripetal,
+/// ).unwrap();
+/// let table = ArcLengthTable::new(&spline, 32);
+/// assert!((table.length() - 3.0).abs() < 1.0e-3);
+/// let p = spline.point(table.parameter(2.0));
+/// assert!((p[0] - 2.0).abs() < 1.0e-2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArcLengthTable<V>
+where
+    V: Float,
+{
+    /// parameters
+    parameters: Vec<V>,
+    /// lengths
+    lengths: Vec<V>,
+}
+// ============================================================================
+impl<V> ArcLengthTable<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    ///
+    /// Samples each segment `samples` times.
+    pub fn new<S>(spline: &S, samples: usize) -> Self
+    where
+        S: Spline<V>,
+    {
+        let samples = samples.max(1);
+        let n = spline.segments() * samples;
+        let mut parameters = Vec::with_capacity(n + 1);
+        let mut lengths = Vec::with_capacity(n + 1);
+        let step = V::one() / V::from(samples).unwrap();
+        let mut prev = spline.point(V::zero());
+        let mut length = V::zero();
+        parameters.push(V::zero());
+        lengths.push(V::zero());
+        for k in 1..=n {
+            let u = V::from(k).unwrap() * step;
+            let p = spline.point(u);
+            length += (p - prev).length();
+            parameters.push(u);
+            lengths.push(length);
+            prev = p;
+        }
+        ArcLengthTable {
+            parameters,
+            lengths,
+        }
+    }
+    // ========================================================================
+    /// length
+    ///
+    /// The total length.
+    pub fn length(&self) -> V {
+        self.lengths[self.lengths.len() - 1]
+    }
+    // ------------------------------------------------------------------------
+    /// distance
+    ///
+    /// The distance from the start to the parameter `u`.
+    pub fn distance(&self, u: V) -> V {
+        Self::map(&self.parameters, &self.lengths, u)
+    }
+    // ------------------------------------------------------------------------
+    /// parameter
+    ///
+    /// The parameter at the distance `s` from the start, clamped to the
+    /// spline.
+    pub fn parameter(&self, s: V) -> V {
+        Self::map(&self.lengths, &self.parameters, s)
+    }
+    // ========================================================================
+    /// map
+    ///
+    /// Linear interpolation of `dst` at `x` in the ascending `src`.
+    fn map(src: &[V], dst: &[V], x: V) -> V {
+        let last = src.len() - 1;
+        if x <= src[0] {
+            return dst[0];
+        }
+        if src[last] <= x {
+            return dst[last];
+        }
+        let i = src.partition_point(|v| *v <= x).max(1);
+        let d = src[i] - src[i - 1];
+        if d < V::epsilon() {
+            dst[i]
+        } else {
+            dst[i - 1] + (dst[i] - dst[i - 1]) * ((x - src[i - 1]) / d)
+        }
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::assert_approx_eq;
+    use alloc::vec;
+    // ========================================================================
+    fn points() -> Vec<Vector3<f64>> {
+        vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(3.0, 2.0, 1.0),
+            Vector3::new(4.0, 0.0, 1.0),
+            Vector3::new(6.0, 1.0, 0.0),
+        ]
+    }
+    // ------------------------------------------------------------------------
+    /// The tangent against a central difference.
+    fn check_tangent<S: Spline<f64, Vector = Vector3<f64>>>(spline: &S) {
+        let h = 1.0e-6;
+        let n = spline.segments() as f64;
+        for k in 0..20 {
+            let u = n * (f64::from(k) + 0.5) / 20.0;
+            let d = (spline.point(u + h) - spline.point(u - h)) * (0.5 / h);
+            assert_approx_eq!(spline.tangent(u), d, epsilon = 1.0e-5);
+        }
+    }
+    // ========================================================================
+    #[test]
+    fn catmull_rom() {
+        for &t in &[
+            CatmullRomType::Uniform,
+            CatmullRomType::Centripetal,
+            CatmullRomType::Chordal,
+        ] {
+            let s = CatmullRom::new(points(), t).unwrap();
+            assert_eq!(s.segments(), 4);
+            for (i, p) in points().iter().enumerate() {
+                assert_approx_eq!(s.point(i as f64), *p, epsilon = 1.0e-12);
+            }
+            check_tangent(&s);
+        }
+        assert!(CatmullRom::<f64, Vector3<f64>>::new(
+            vec![Vector3::default()],
+            CatmullRomType::Uniform
+        )
+        .is_err());
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn hermite() {
+        let t = vec![Vector3::new(1.0, 0.0, 0.0); 5];
+        let s = Hermite::new(points(), t).unwrap();
+        assert_approx_eq!(s.point(2.0), points()[2], epsilon = 1.0e-12);
+        assert_approx_eq!(
+            s.tangent(2.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            epsilon = 1.0e-12
+        );
+        check_tangent(&s);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn b_spline() {
+        let s = BSpline::new(points()).unwrap();
+        assert_eq!(s.segments(), 2);
+        let p = points();
+        assert_approx_eq!(
+            s.point(0.0),
+            (p[0] + p[1] * 4.0 + p[2]) * (1.0 / 6.0),
+            epsilon = 1.0e-12
+        );
+        // C1 at the joint
+        assert_approx_eq!(
+            s.segment_tangent(0, 1.0),
+            s.segment_tangent(1, 0.0),
+            epsilon = 1.0e-12
+        );
+        check_tangent(&s);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn bezier_path() {
+        let mut p = points();
+        p.extend_from_slice(&[
+            Vector3::new(7.0, 2.0, 0.0),
+            Vector3::new(8.0, 2.0, 0.0),
+        ]);
+        let s = BezierPath::new(p.clone()).unwrap();
+        assert_eq!(s.segments(), 2);
+        assert_approx_eq!(s.point(1.0), p[3], epsilon = 1.0e-12);
+        assert_approx_eq!(s.point(2.0), p[6], epsilon = 1.0e-12);
+        assert_approx_eq!(
+            s.tangent(0.0),
+            (p[1] - p[0]) * 3.0,
+            epsilon = 1.0e-12
+        );
+        check_tangent(&s);
+        assert!(BezierPath::new(points()).is_err());
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn arc_length() {
+        let s =
+            CatmullRom::new(points(), CatmullRomType::Centripetal).unwrap();
+        let table = ArcLengthTable::new(&s, 256);
+        let l = table.length();
+        // constant speed
+        let step = l / 50.0;
+        let mut prev = s.point(0.0);
+        for k in 1..=50 {
+            let p = s.point(table.parameter(step * f64::from(k)));
+            // a chord is a little shorter than its arc at the bends
+            assert!(((p - prev).length() - step).abs() < step * 2.0e-2);
+            prev = p;
+        }
+        assert!(
+            (table.distance(table.parameter(l * 0.3)) - l * 0.3).abs()
+                < 1.0e-9
+        );
+        assert_eq!(table.parameter(-1.0), 0.0);
+        assert_eq!(table.parameter(l + 1.0), 4.0);
+    }
+}
@@ -6,11 +6,11 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/04/19
-//  @date 2019/07/09
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
-use std::ops::{
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub,
     SubAssign,
 };
@@ -19,14 +19,22 @@ use super::{Cleanup, Float};
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// vector_define!
+///
+/// `$fast` names the module whose `add`, `sub`, `mul_scalar` and `dot` are
+/// tried first when the `simd` feature is enabled.
 macro_rules! vector_define {
-    ($name:ident, $n:expr) => {
+    ($name:ident, $n:expr $(, $fast:ident)?) => {
         // ////////////////////////////////////////////////////////////////////
         // ====================================================================
         /// struct $name
         #[derive(
             Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
         )]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
         pub struct $name<V: Float>([V; $n]);
         // ====================================================================
         impl<V> From<[V; $n]> for $name<V>
@@ -48,6 +56,16 @@ macro_rules! vector_define {
                 $name::from(inner)
             }
         }
+        // --------------------------------------------------------------------
+        impl From<$name<f32>> for $name<f64> {
+            fn from(src: $name<f32>) -> Self {
+                let mut inner = [0.0; $n];
+                for (d, s) in inner.iter_mut().zip(src.0.iter()) {
+                    *d = f64::from(*s);
+                }
+                $name(inner)
+            }
+        }
         // ====================================================================
         impl<V> Index<usize> for $name<V>
         where
@@ -124,6 +142,14 @@ macro_rules! vector_define {
         {
             type Output = Self;
             fn mul(self, rhs: V) -> Self::Output {
+                $(
+                    #[cfg(feature = "simd")]
+                    {
+                        if let Some(r) = super::$fast::mul_scalar(&self, rhs) {
+                            return r;
+                        }
+                    }
+                )?
                 let mut inner = [V::default(); $n];
                 for (i, v) in inner.iter_mut().enumerate() {
                     *v = self.0[i] * rhs;
@@ -174,6 +200,14 @@ macro_rules! vector_define {
         {
             type Output = Self;
             fn add(self, rhs: Self) -> Self::Output {
+                $(
+                    #[cfg(feature = "simd")]
+                    {
+                        if let Some(r) = super::$fast::add(&self, &rhs) {
+                            return r;
+                        }
+                    }
+                )?
                 let mut inner = [V::default(); $n];
                 for (i, v) in inner.iter_mut().enumerate() {
                     *v = self.0[i] + rhs.0[i];
@@ -199,6 +233,14 @@ macro_rules! vector_define {
         {
             type Output = Self;
             fn sub(self, rhs: Self) -> Self::Output {
+                $(
+                    #[cfg(feature = "simd")]
+                    {
+                        if let Some(r) = super::$fast::sub(&self, &rhs) {
+                            return r;
+                        }
+                    }
+                )?
                 let mut inner = [V::default(); $n];
                 for (i, v) in inner.iter_mut().enumerate() {
                     *v = self.0[i] - rhs.0[i];
@@ -282,6 +324,31 @@ macro_rules! vector_define {
             pub fn from_no_clean(inner: [V; $n]) -> Self {
                 $name(inner)
             }
+            // ----------------------------------------------------------------
+            /// cast
+            ///
+            /// `None` if a finite component overflows `U`, which `NumCast`
+            /// alone would round to infinity.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ::sif_math::Vector3;
+            ///
+            /// let v = Vector3::new(1.0f64, 2.0, 3.0);
+            /// assert_eq!(v.cast::<f32>(), Some(Vector3::new(1.0, 2.0, 3.0)));
+            /// assert_eq!(Vector3::new(1.0e300f64, 0.0, 0.0).cast::<f32>(), None);
+            /// ```
+            pub fn cast<U: Float>(&self) -> Option<$name<U>> {
+                let mut inner = [U::zero(); $n];
+                for (d, s) in inner.iter_mut().zip(self.0.iter()) {
+                    *d = U::from(*s)?;
+                    if s.is_finite() && !d.is_finite() {
+                        return None;
+                    }
+                }
+                Some($name(inner))
+            }
             // ================================================================
             /// as_ptr
             pub fn as_ptr(&self) -> *const V {
@@ -294,12 +361,12 @@ macro_rules! vector_define {
             }
             // ================================================================
             /// iter
-            pub fn iter(&self) -> ::std::slice::Iter<'_, V> {
+            pub fn iter(&self) -> ::core::slice::Iter<'_, V> {
                 self.0.iter()
             }
             // ----------------------------------------------------------------
             /// iter_mut
-            pub fn iter_mut(&mut self) -> ::std::slice::IterMut<'_, V> {
+            pub fn iter_mut(&mut self) -> ::core::slice::IterMut<'_, V> {
                 self.0.iter_mut()
             }
             // ================================================================
@@ -327,6 +394,14 @@ macro_rules! vector_define {
             ///            0.0);
             /// ```
             pub fn dot(&self, rhs: &Self) -> V {
+                $(
+                    #[cfg(feature = "simd")]
+                    {
+                        if let Some(r) = super::$fast::dot(self, rhs) {
+                            return r;
+                        }
+                    }
+                )?
                 let mut ret = V::zero();
                 for i in 0..$n {
                     ret += self.0[i] * rhs.0[i]
@@ -358,6 +433,113 @@ macro_rules! vector_define {
                 }
                 self
             }
+            // ================================================================
+            /// map
+            fn map(&self, f: impl Fn(V) -> V) -> Self {
+                let mut inner = self.0;
+                for i in &mut inner {
+                    *i = f(*i);
+                }
+                Self::from(inner)
+            }
+            // ----------------------------------------------------------------
+            /// zip
+            fn zip(&self, rhs: &Self, f: impl Fn(V, V) -> V) -> Self {
+                let mut inner = self.0;
+                for (i, r) in inner.iter_mut().zip(rhs.0.iter()) {
+                    *i = f(*i, *r);
+                }
+                Self::from(inner)
+            }
+            // ----------------------------------------------------------------
+            /// min
+            pub fn min(&self, rhs: &Self) -> Self {
+                self.zip(rhs, V::min)
+            }
+            // ----------------------------------------------------------------
+            /// max
+            pub fn max(&self, rhs: &Self) -> Self {
+                self.zip(rhs, V::max)
+            }
+            // ----------------------------------------------------------------
+            /// clamp
+            pub fn clamp(&self, lo: &Self, hi: &Self) -> Self {
+                self.max(lo).min(hi)
+            }
+            // ----------------------------------------------------------------
+            /// abs
+            pub fn abs(&self) -> Self {
+                self.map(V::abs)
+            }
+            // ----------------------------------------------------------------
+            /// floor
+            pub fn floor(&self) -> Self {
+                self.map(V::floor)
+            }
+            // ----------------------------------------------------------------
+            /// mul_elem
+            ///
+            /// The same as `*`, by reference.
+            pub fn mul_elem(&self, rhs: &Self) -> Self {
+                self.zip(rhs, |a, b| a * b)
+            }
+            // ================================================================
+            /// reflect
+            ///
+            /// `self` reflected by the surface of the unit `normal`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ::sif_math::Vector2;
+            ///
+            /// let n = Vector2::new(0.0f32, 1.0);
+            /// assert_eq!(Vector2::new(1.0f32, -1.0).reflect(&n),
+            ///            Vector2::new(1.0, 1.0));
+            /// ```
+            pub fn reflect(&self, normal: &Self) -> Self {
+                let d = self.dot(normal);
+                *self - *normal * (d + d)
+            }
+            // ----------------------------------------------------------------
+            /// refract
+            ///
+            /// The unit `self` refracted by the surface of the unit `normal`
+            /// with the ratio `eta` of the indices of refraction.
+            /// `None` on the total internal reflection.
+            pub fn refract(&self, normal: &Self, eta: V) -> Option<Self> {
+                let d = self.dot(normal);
+                let k = V::one() - eta * eta * (V::one() - d * d);
+                if k < V::zero() {
+                    None
+                } else {
+                    Some(*self * eta - *normal * (eta * d + k.sqrt()))
+                }
+            }
+            // ----------------------------------------------------------------
+            /// project_onto
+            ///
+            /// Zero if `rhs` is zero.
+            pub fn project_onto(&self, rhs: &Self) -> Self {
+                let l2 = rhs.length2();
+                if l2 == V::zero() {
+                    Self::default()
+                } else {
+                    *rhs * (self.dot(rhs) / l2)
+                }
+            }
+            // ----------------------------------------------------------------
+            /// angle_between
+            ///
+            /// In `[0, π]`, zero if either is zero.
+            pub fn angle_between(&self, rhs: &Self) -> V {
+                let l = self.length() * rhs.length();
+                if l == V::zero() {
+                    V::zero()
+                } else {
+                    (self.dot(rhs) / l).max(-V::one()).min(V::one()).acos()
+                }
+            }
         }
     };
 }
@@ -428,7 +610,7 @@ impl<V: Float> From<Vector4<V>> for Vector3<V> {
 }
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
-vector_define!(Vector4, 4);
+vector_define!(Vector4, 4, simd);
 // ============================================================================
 impl<V: Float> Vector4<V> {
     // ========================================================================
@@ -462,4 +644,77 @@ mod tests {
         let mut v1 = Vector4::new(1.0f32, 1.0, 1.0, 1.0);
         assert_eq!(1.0f32, v1.normalize().length());
     }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn component_wise() {
+        let a = Vector3::new(1.0f32, -2.5, 3.0);
+        let b = Vector3::new(-1.0f32, 2.0, 4.0);
+        assert_eq!(a.min(&b), Vector3::new(-1.0, -2.5, 3.0));
+        assert_eq!(a.max(&b), Vector3::new(1.0, 2.0, 4.0));
+        assert_eq!(a.abs(), Vector3::new(1.0, 2.5, 3.0));
+        assert_eq!(a.floor(), Vector3::new(1.0, -3.0, 3.0));
+        assert_eq!(a.mul_elem(&b), a * b);
+        let (lo, hi) =
+            (Vector3::new(0.0f32, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+        assert_eq!(a.clamp(&lo, &hi), Vector3::new(1.0, 0.0, 2.0));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn geometric() {
+        let n = Vector2::new(0.0f64, 1.0);
+        let s = 0.5f64.sqrt();
+        let i = Vector2::new(s, -s);
+        assert_eq!(i.reflect(&n), Vector2::new(s, s));
+        // Snell, sin(t) = eta * sin(i)
+        let t = i.refract(&n, 1.0 / 1.5).unwrap();
+        assert!((t.length() - 1.0).abs() < 1.0e-12);
+        assert!((t[0] - s / 1.5).abs() < 1.0e-12 && t[1] < 0.0);
+        assert_eq!(i.refract(&n, 1.5), None);
+        let a = Vector3::new(2.0f64, 3.0, 4.0);
+        assert_eq!(
+            a.project_onto(&Vector3::new(0.0, 0.0, 2.0)),
+            Vector3::new(0.0, 0.0, 4.0)
+        );
+        assert_eq!(a.project_onto(&Vector3::default()), Vector3::default());
+        let x = Vector3::new(1.0f64, 0.0, 0.0);
+        let y = Vector3::new(0.0f64, 3.0, 0.0);
+        let half_pi = ::core::f64::consts::FRAC_PI_2;
+        assert!((x.angle_between(&y) - half_pi).abs() < 1.0e-12);
+        assert!(
+            (x.angle_between(&(x * -2.0)) - 2.0 * half_pi).abs() < 1.0e-12
+        );
+        assert_eq!(x.angle_between(&x), 0.0);
+        // small magnitudes are not taken for zero
+        let x = Vector3::new(1.0e-4f32, 0.0, 0.0);
+        let y = Vector3::new(0.0f32, 1.0e-4, 0.0);
+        let half_pi = ::core::f32::consts::FRAC_PI_2;
+        assert!((x.angle_between(&y) - half_pi).abs() < 1.0e-6);
+        let p = Vector3::new(3.0f32, 4.0, 0.0).project_onto(&x);
+        assert!((p - Vector3::new(3.0, 0.0, 0.0)).length() < 1.0e-6);
+        assert_eq!(x.angle_between(&Vector3::default()), 0.0);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn cast() {
+        let v = Vector4::new(1.0f32, -2.5, 3.0, 0.125);
+        let w = Vector4::<f64>::from(v);
+        assert_eq!(w, Vector4::new(1.0f64, -2.5, 3.0, 0.125));
+        assert_eq!(w.cast::<f32>(), Some(v));
+        assert_eq!(Vector2::new(f64::MAX, 0.0).cast::<f32>(), None);
+        // the cleanup is not applied
+        let tiny = Vector2::new(1.0e-30f64, 1.0).cast::<f32>().unwrap();
+        assert_eq!(tiny[0], 1.0e-30f32);
+    }
+    // ========================================================================
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let v = Vector3::new(1.0f32, -2.5, 3.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,-2.5,3.0]");
+        assert_eq!(v, serde_json::from_str::<Vector3<f32>>(&json).unwrap());
+        let bin = bincode::serialize(&v).unwrap();
+        assert_eq!(bin.len(), 12);
+        assert_eq!(v, bincode::deserialize::<Vector3<f32>>(&bin).unwrap());
+    }
 }
@@ -0,0 +1,56 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! no_std.rs
+//!
+//! Uses the crate from a `#![no_std]` crate.
+//! `cargo test --no-default-features` builds `sif_math` itself without std.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// attribute  =================================================================
+#![no_std]
+// extern  ====================================================================
+extern crate alloc;
+// use  =======================================================================
+use alloc::vec;
+// ----------------------------------------------------------------------------
+use sif_math::{
+    ArcLengthTable, CatmullRom, CatmullRomType, Matrix4x4, Noise, Quaternion,
+    Simplex, Spline, Vector3, Vector4,
+};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[test]
+fn math() {
+    let q = Quaternion::from_axis_angle(
+        &Vector3::new(0.0f32, 0.0, 1.0),
+        core::f32::consts::FRAC_PI_2,
+    )
+    .unwrap();
+    let v = q.rotate(&Vector3::new(1.0, 0.0, 0.0));
+    assert!((v - Vector3::new(0.0, 1.0, 0.0)).length() < 1.0e-6);
+    let m = Matrix4x4::<f32>::default();
+    assert_eq!(
+        m * Vector4::new(1.0, 2.0, 3.0, 1.0),
+        Vector4::new(1.0, 2.0, 3.0, 1.0)
+    );
+}
+// ----------------------------------------------------------------------------
+#[test]
+fn alloc() {
+    let s = CatmullRom::new(
+        vec![Vector3::new(0.0f64, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0)],
+        CatmullRomType::Uniform,
+    )
+    .unwrap();
+    let table = ArcLengthTable::new(&s, 8);
+    assert!((table.length() - 2.0).abs() < 1.0e-9);
+    assert!((s.point(table.parameter(1.0))[0] - 1.0).abs() < 1.0e-9);
+    let n: f64 = Simplex::new(0).noise(&Vector3::new(0.5, 0.5, 0.5));
+    assert!(n.abs() <= 1.0);
+}
@@ -6,22 +6,38 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/12
-//  @date 2018/05/12
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 pub use self::capsule::*;
+pub use self::closest::*;
+pub use self::collide::*;
 pub use self::cube::*;
 pub use self::cuboid::*;
+pub use self::frustum::*;
+pub use self::gjk::*;
+pub use self::obb::*;
 pub use self::plane::*;
 pub use self::ray::*;
+pub use self::ray_cast::*;
 pub use self::segment::*;
 pub use self::sphere::*;
+pub use self::support::*;
+pub use self::triangle::*;
 // mod  =======================================================================
 mod capsule;
+mod closest;
+mod collide;
 mod cube;
 mod cuboid;
+mod frustum;
+mod gjk;
+mod obb;
 mod plane;
 mod ray;
+mod ray_cast;
 mod segment;
 mod sphere;
+mod support;
+mod triangle;
@@ -0,0 +1,570 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! closest.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Vector3};
+// ----------------------------------------------------------------------------
+use super::{
+    Capsule, Cube, Cuboid, Frustum, Obb, Plane, Ray, Segment, Sphere, Triangle,
+};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait Distance
+pub trait Distance<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// closest_point
+    ///
+    /// The nearest point on the surface to `p`.
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V>;
+    // ------------------------------------------------------------------------
+    /// signed_distance
+    ///
+    /// Negative inside a solid. Never negative for `Ray`, `Segment` and
+    /// `Triangle`, which have no inside.
+    fn signed_distance(&self, p: &Vector3<V>) -> V;
+    // ------------------------------------------------------------------------
+    /// distance
+    ///
+    /// Zero inside a solid.
+    fn distance(&self, p: &Vector3<V>) -> V {
+        self.signed_distance(p).max(V::zero())
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// closest_point_segment
+pub fn closest_point_segment<V: Float>(
+    p: &Vector3<V>,
+    a: &Vector3<V>,
+    b: &Vector3<V>,
+) -> Vector3<V> {
+    let ab = *b - *a;
+    let l2 = ab.length2();
+    if l2 == V::zero() {
+        return *a;
+    }
+    *a + ab * ((*p - *a).dot(&ab) / l2).max(V::zero()).min(V::one())
+}
+// ----------------------------------------------------------------------------
+/// closest_segment_segment
+///
+/// The closest points of the segments `p0`-`q0` and `p1`-`q1`.
+pub fn closest_segment_segment<V: Float>(
+    p0: &Vector3<V>,
+    q0: &Vector3<V>,
+    p1: &Vector3<V>,
+    q1: &Vector3<V>,
+) -> (Vector3<V>, Vector3<V>) {
+    let clamp = |x: V| x.max(V::zero()).min(V::one());
+    let (d0, d1, r) = (*q0 - *p0, *q1 - *p1, *p0 - *p1);
+    let (a, e, f) = (d0.length2(), d1.length2(), d1.dot(&r));
+    let zero = V::zero();
+    let (s, t) = if a == zero && e == zero {
+        (V::zero(), V::zero())
+    } else if a == zero {
+        (V::zero(), clamp(f / e))
+    } else {
+        let c = d0.dot(&r);
+        if e == zero {
+            (clamp(-c / a), V::zero())
+        } else {
+            let b = d0.dot(&d1);
+            // `a * e` times the square of the sine between them
+            let denom = a * e - b * b;
+            let s = if V::epsilon() * a * e < denom {
+                clamp((b * f - c * e) / denom)
+            } else {
+                V::zero()
+            };
+            let t = (b * s + f) / e;
+            if t < V::zero() {
+                (clamp(-c / a), V::zero())
+            } else if V::one() < t {
+                (clamp((b - c) / a), V::one())
+            } else {
+                (s, t)
+            }
+        }
+    };
+    (*p0 + d0 * s, *p1 + d1 * t)
+}
+// ----------------------------------------------------------------------------
+/// closest_point_triangle
+///
+/// By the Voronoi regions of the triangle `a`, `b`, `c`.
+pub fn closest_point_triangle<V: Float>(
+    p: &Vector3<V>,
+    a: &Vector3<V>,
+    b: &Vector3<V>,
+    c: &Vector3<V>,
+) -> Vector3<V> {
+    let (ab, ac, ap) = (*b - *a, *c - *a, *p - *a);
+    let (d1, d2) = (ab.dot(&ap), ac.dot(&ap));
+    if d1 <= V::zero() && d2 <= V::zero() {
+        return *a;
+    }
+    let bp = *p - *b;
+    let (d3, d4) = (ab.dot(&bp), ac.dot(&bp));
+    if V::zero() <= d3 && d4 <= d3 {
+        return *b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= V::zero() && V::zero() <= d1 && d3 <= V::zero() {
+        return *a + ab * (d1 / (d1 - d3));
+    }
+    let cp = *p - *c;
+    let (d5, d6) = (ab.dot(&cp), ac.dot(&cp));
+    if V::zero() <= d6 && d5 <= d6 {
+        return *c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= V::zero() && V::zero() <= d2 && d6 <= V::zero() {
+        return *a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= V::zero() && d3 <= d4 && d6 <= d5 {
+        return *b + (*c - *b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    // `|ab x ac|^2`
+    let sum = va + vb + vc;
+    if sum <= V::epsilon() * ab.length2() * ac.length2() {
+        // degenerate, the nearest of the edges
+        let e = [
+            closest_point_segment(p, a, b),
+            closest_point_segment(p, b, c),
+            closest_point_segment(p, c, a),
+        ];
+        return nearest(p, &e);
+    }
+    *a + ab * (vb / sum) + ac * (vc / sum)
+}
+// ----------------------------------------------------------------------------
+/// closest_segment_triangle
+///
+/// The closest points of the segment `p`-`q` and the triangle `a`, `b`, `c`,
+/// the same point while they cross.
+pub fn closest_segment_triangle<V: Float>(
+    p: &Vector3<V>,
+    q: &Vector3<V>,
+    a: &Vector3<V>,
+    b: &Vector3<V>,
+    c: &Vector3<V>,
+) -> (Vector3<V>, Vector3<V>) {
+    if let Some(h) = Segment::new(*p, *q).intersect(&Triangle::new(*a, *b, *c))
+    {
+        return (h.point, h.point);
+    }
+    let mut ret = (*p, closest_point_triangle(p, a, b, c));
+    let mut take = |pair: (Vector3<V>, Vector3<V>)| {
+        if (pair.0 - pair.1).length2() < (ret.0 - ret.1).length2() {
+            ret = pair;
+        }
+    };
+    take((*q, closest_point_triangle(q, a, b, c)));
+    take(closest_segment_segment(p, q, a, b));
+    take(closest_segment_segment(p, q, b, c));
+    take(closest_segment_segment(p, q, c, a));
+    ret
+}
+// ----------------------------------------------------------------------------
+/// nearest
+fn nearest<V: Float>(p: &Vector3<V>, src: &[Vector3<V>]) -> Vector3<V> {
+    let mut ret = src[0];
+    for q in &src[1..] {
+        if (*q - *p).length2() < (ret - *p).length2() {
+            ret = *q;
+        }
+    }
+    ret
+}
+// ----------------------------------------------------------------------------
+/// around
+///
+/// The point at `radius` from `center` toward `p`, `+Z` if they meet.
+fn around<V: Float>(
+    center: &Vector3<V>,
+    radius: V,
+    p: &Vector3<V>,
+) -> Vector3<V> {
+    let d = *p - *center;
+    let l = d.length();
+    if l == V::zero() {
+        return *center + Vector3::new(V::zero(), V::zero(), radius);
+    }
+    *center + d * (radius / l)
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+impl<V> Distance<V> for Sphere<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        around(&self.center, self.radius, p)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        (*p - self.center).length() - self.radius
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Obb<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        let l = self.to_local(p);
+        let mut q = l.clamp(&(self.radius * -V::one()), &self.radius);
+        if q == l {
+            // inside, onto the nearest face
+            let mut i = 0;
+            for k in 1..3 {
+                if self.radius[k] - l[k].abs() < self.radius[i] - l[i].abs() {
+                    i = k;
+                }
+            }
+            q[i] = if l[i] < V::zero() {
+                -self.radius[i]
+            } else {
+                self.radius[i]
+            };
+        }
+        self.to_world(&q)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        let q = self.to_local(p).abs() - self.radius;
+        let outside = q.max(&Vector3::default()).length();
+        outside + q[0].max(q[1]).max(q[2]).min(V::zero())
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Cube<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        Obb::from(self.clone()).closest_point(p)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        Obb::from(self.clone()).signed_distance(p)
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Cuboid<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        Obb::from(self.clone()).closest_point(p)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        Obb::from(self.clone()).signed_distance(p)
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Capsule<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        let q = closest_point_segment(p, &self.start, &self.end);
+        around(&q, self.radius, p)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        let q = closest_point_segment(p, &self.start, &self.end);
+        (*p - q).length() - self.radius
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Plane<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        *p - *self.as_normal() * Plane::signed_distance(self, p)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        Plane::signed_distance(self, p)
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Triangle<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        closest_point_triangle(p, &self.a, &self.b, &self.c)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        (*p - self.closest_point(p)).length()
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Segment<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        closest_point_segment(p, &self.start, &self.end)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        (*p - self.closest_point(p)).length()
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Ray<V>
+where
+    V: Float,
+{
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        let d = self.direction();
+        self.start + d * (*p - self.start).dot(&d).max(V::zero())
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        (*p - self.closest_point(p)).length()
+    }
+}
+// ============================================================================
+impl<V> Distance<V> for Frustum<V>
+where
+    V: Float,
+{
+    /// Onto the nearest plane from inside, onto the nearest of the twelve
+    /// triangles of the faces from outside. The closest point is no deeper
+    /// than twice the distance to a near corner, so an infinite frustum is
+    /// truncated there.
+    fn closest_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        let (i, d) = self.nearest_plane(p);
+        if V::zero() <= d {
+            return *p - *self.planes()[i].as_normal() * d;
+        }
+        let depth = (*p - self.corners()[0]).length() * V::from(2).unwrap();
+        let c = self.truncated(depth);
+        let faces = [
+            [0, 2, 6, 4],
+            [1, 3, 7, 5],
+            [0, 1, 5, 4],
+            [2, 3, 7, 6],
+            [0, 1, 3, 2],
+            [4, 5, 7, 6],
+        ];
+        let mut q = Vec::with_capacity(12);
+        for f in &faces {
+            q.push(closest_point_triangle(p, &c[f[0]], &c[f[1]], &c[f[2]]));
+            q.push(closest_point_triangle(p, &c[f[0]], &c[f[2]], &c[f[3]]));
+        }
+        nearest(p, &q)
+    }
+    fn signed_distance(&self, p: &Vector3<V>) -> V {
+        let (_, d) = self.nearest_plane(p);
+        if V::zero() <= d {
+            -d
+        } else {
+            (*p - self.closest_point(p)).length()
+        }
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::super::ClipDepth;
+    use super::*;
+    use crate::test_util::v;
+    use sif_math::{Matrix4x4, Quaternion};
+    // ========================================================================
+    fn near(a: Vector3<f64>, b: Vector3<f64>) {
+        assert!((a - b).length() < 1.0e-9, "{:?} {:?}", a, b);
+    }
+    // ========================================================================
+    #[test]
+    fn segments() {
+        let (a, b) = closest_segment_segment(
+            &v(-1.0, 0.0, 0.0),
+            &v(1.0, 0.0, 0.0),
+            &v(0.5, -1.0, 2.0),
+            &v(0.5, 1.0, 2.0),
+        );
+        near(a, v(0.5, 0.0, 0.0));
+        near(b, v(0.5, 0.0, 2.0));
+        // beyond the ends
+        let (a, b) = closest_segment_segment(
+            &v(0.0, 0.0, 0.0),
+            &v(1.0, 0.0, 0.0),
+            &v(3.0, 1.0, 0.0),
+            &v(3.0, 5.0, 0.0),
+        );
+        near(a, v(1.0, 0.0, 0.0));
+        near(b, v(3.0, 1.0, 0.0));
+        // parallel and degenerate
+        let (a, b) = closest_segment_segment(
+            &v(0.0, 0.0, 0.0),
+            &v(1.0, 0.0, 0.0),
+            &v(0.0, 1.0, 0.0),
+            &v(1.0, 1.0, 0.0),
+        );
+        assert!(((a - b).length() - 1.0).abs() < 1.0e-9);
+        let p = v(2.0, 2.0, 2.0);
+        let (a, b) = closest_segment_segment(
+            &p,
+            &p,
+            &v(0.0, 0.0, 0.0),
+            &v(4.0, 0.0, 0.0),
+        );
+        near(a, p);
+        near(b, v(2.0, 0.0, 0.0));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn triangle() {
+        let (a, b, c) = (v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(0.0, 2.0, 0.0));
+        near(
+            closest_point_triangle(&v(0.5, 0.5, 3.0), &a, &b, &c),
+            v(0.5, 0.5, 0.0),
+        );
+        near(closest_point_triangle(&v(-1.0, -1.0, 1.0), &a, &b, &c), a);
+        near(closest_point_triangle(&v(3.0, -1.0, 0.0), &a, &b, &c), b);
+        near(
+            closest_point_triangle(&v(1.0, -1.0, 0.0), &a, &b, &c),
+            v(1.0, 0.0, 0.0),
+        );
+        near(
+            closest_point_triangle(&v(2.0, 2.0, 0.0), &a, &b, &c),
+            v(1.0, 1.0, 0.0),
+        );
+        let t = Triangle::new(a, b, c);
+        assert!((t.signed_distance(&v(0.5, 0.5, -3.0)) - 3.0).abs() < 1.0e-9);
+        // crossing, above and aside
+        let (p, q) = closest_segment_triangle(
+            &v(0.5, 0.5, -1.0),
+            &v(0.5, 0.5, 1.0),
+            &a,
+            &b,
+            &c,
+        );
+        near(p, v(0.5, 0.5, 0.0));
+        near(q, v(0.5, 0.5, 0.0));
+        let (p, q) = closest_segment_triangle(
+            &v(0.5, 0.5, 1.0),
+            &v(0.2, 0.2, 3.0),
+            &a,
+            &b,
+            &c,
+        );
+        near(p, v(0.5, 0.5, 1.0));
+        near(q, v(0.5, 0.5, 0.0));
+        let (p, q) = closest_segment_triangle(
+            &v(3.0, 3.0, -1.0),
+            &v(3.0, 3.0, 1.0),
+            &a,
+            &b,
+            &c,
+        );
+        near(p, v(3.0, 3.0, 0.0));
+        near(q, v(1.0, 1.0, 0.0));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn small_triangle() {
+        let w = |x: f32, y: f32, z: f32| Vector3::new(x, y, z);
+        let (a, b, c) =
+            (w(0.0, 0.0, 0.0), w(0.01, 0.0, 0.0), w(0.0, 0.01, 0.0));
+        let near = |p: Vector3<f32>, q: Vector3<f32>| {
+            assert!((p - q).length() < 1.0e-7, "{:?} {:?}", p, q);
+        };
+        let p = w(0.002, 0.002, 0.01);
+        near(closest_point_triangle(&p, &a, &b, &c), w(0.002, 0.002, 0.0));
+        let t = Triangle::new(a, b, c);
+        assert!((t.distance(&p) - 0.01).abs() < 1.0e-7);
+        let (s, q) =
+            closest_segment_triangle(&p, &w(0.003, 0.002, 0.02), &a, &b, &c);
+        near(s, p);
+        near(q, w(0.002, 0.002, 0.0));
+        // parallel to an edge
+        let (s, q) = closest_segment_segment(
+            &w(0.002, -0.001, 0.0),
+            &w(0.004, -0.001, 0.0),
+            &a,
+            &b,
+        );
+        assert!(((s - q).length() - 0.001).abs() < 1.0e-7);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn solids() {
+        let s = Sphere::new(v(1.0, 0.0, 0.0), 2.0);
+        assert!((s.signed_distance(&v(1.0, 0.0, 5.0)) - 3.0).abs() < 1.0e-9);
+        near(s.closest_point(&v(1.0, 0.0, 0.5)), v(1.0, 0.0, 2.0));
+        assert!((s.signed_distance(&v(1.0, 0.0, 0.5)) + 1.5).abs() < 1.0e-9);
+
+        let c = Cuboid::new(v(0.0, 0.0, 0.0), v(1.0, 2.0, 3.0));
+        near(c.closest_point(&v(4.0, 6.0, 0.0)), v(1.0, 2.0, 0.0));
+        assert!((c.signed_distance(&v(4.0, 6.0, 0.0)) - 5.0).abs() < 1.0e-9);
+        near(c.closest_point(&v(0.0, 1.5, 0.0)), v(0.0, 2.0, 0.0));
+        assert!((c.signed_distance(&v(0.0, 1.5, 0.0)) + 0.5).abs() < 1.0e-9);
+        assert_eq!(
+            Cube::new(v(0.0, 0.0, 0.0), 1.0).distance(&v(0.5, 0.0, 0.0)),
+            0.0
+        );
+
+        let q = Quaternion::from_axis_angle(
+            &v(0.0, 0.0, 1.0),
+            0.25 * ::std::f64::consts::PI,
+        )
+        .unwrap();
+        let o = Obb::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0), q);
+        assert!(
+            (o.signed_distance(&v(3.0, 0.0, 0.0)) - (3.0 - 2.0f64.sqrt()))
+                .abs()
+                < 1.0e-9
+        );
+        near(
+            o.closest_point(&v(3.0, 0.0, 0.0)),
+            v(2.0f64.sqrt(), 0.0, 0.0),
+        );
+
+        let c = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 4.0), 1.0);
+        near(c.closest_point(&v(3.0, 0.0, 2.0)), v(1.0, 0.0, 2.0));
+        assert!((c.signed_distance(&v(0.0, 0.0, -3.0)) - 2.0).abs() < 1.0e-9);
+
+        let p = Plane::new(&mut v(0.0, 0.0, 1.0), 1.0);
+        near(
+            Distance::closest_point(&p, &v(3.0, 2.0, -4.0)),
+            v(3.0, 2.0, 1.0),
+        );
+        assert_eq!(Distance::signed_distance(&p, &v(3.0, 2.0, -4.0)), -5.0);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn lines() {
+        let s = Segment::new(v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0));
+        near(s.closest_point(&v(5.0, 1.0, 0.0)), v(2.0, 0.0, 0.0));
+        let r = Ray::new(v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0));
+        near(r.closest_point(&v(5.0, 1.0, 0.0)), v(5.0, 0.0, 0.0));
+        near(r.closest_point(&v(-5.0, 1.0, 0.0)), v(0.0, 0.0, 0.0));
+        assert!((r.signed_distance(&v(5.0, 1.0, 0.0)) - 1.0).abs() < 1.0e-9);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn frustum() {
+        // looking down -Z from the origin, near 1, far 3, 90 degrees
+        let m = Matrix4x4::new_frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+        let f = Frustum::from_matrix(&m, ClipDepth::NegativeOneToOne).unwrap();
+        assert!((f.signed_distance(&v(0.0, 0.0, -1.5)) + 0.5).abs() < 1.0e-9);
+        near(f.closest_point(&v(0.0, 0.0, -1.5)), v(0.0, 0.0, -1.0));
+        near(f.closest_point(&v(0.0, 0.0, 1.0)), v(0.0, 0.0, -1.0));
+        near(f.closest_point(&v(5.0, 5.0, -10.0)), v(3.0, 3.0, -3.0));
+        assert!((f.signed_distance(&v(0.0, 0.0, -5.0)) - 2.0).abs() < 1.0e-9);
+    }
+}
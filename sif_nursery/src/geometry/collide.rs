@@ -0,0 +1,835 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! collide.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Vector3};
+// ----------------------------------------------------------------------------
+use super::{
+    closest_point_segment, closest_segment_segment, Capsule, Cube, Cuboid,
+    Distance, Obb, Plane, Sphere,
+};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct ContactPoint
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContactPoint<V>
+where
+    V: Float,
+{
+    /// point
+    ///
+    /// Midway between the two surfaces.
+    pub point: Vector3<V>,
+    /// depth
+    pub depth: V,
+}
+// ============================================================================
+/// struct Contact
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contact<V>
+where
+    V: Float,
+{
+    /// normal
+    ///
+    /// Unit, from the first shape toward the second.
+    pub normal: Vector3<V>,
+    /// depth
+    ///
+    /// The distance to move the second along `normal` to part them.
+    pub depth: V,
+    /// points
+    pub points: Vec<ContactPoint<V>>,
+}
+// ============================================================================
+impl<V> Contact<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    fn new(normal: Vector3<V>, depth: V, point: Vector3<V>) -> Self {
+        Contact {
+            normal,
+            depth,
+            points: vec![ContactPoint { point, depth }],
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// merge
+    ///
+    /// `None` if `src` is empty.
+    fn merge(normal: Vector3<V>, src: Vec<Contact<V>>) -> Option<Self> {
+        if src.is_empty() {
+            return None;
+        }
+        let depth = src.iter().fold(V::zero(), |d, c| d.max(c.depth));
+        let points = src.into_iter().flat_map(|c| c.points).collect();
+        Some(Contact {
+            normal,
+            depth,
+            points,
+        })
+    }
+    // ========================================================================
+    /// flip
+    ///
+    /// The contact seen from the second shape.
+    pub fn flip(mut self) -> Self {
+        self.normal *= -V::one();
+        self
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait Collide
+///
+/// A `Plane` is the solid half-space behind it.
+pub trait Collide<V, Rhs = Self>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// contact
+    ///
+    /// `None` if apart.
+    fn contact(&self, rhs: &Rhs) -> Option<Contact<V>>;
+    // ------------------------------------------------------------------------
+    /// overlap
+    fn overlap(&self, rhs: &Rhs) -> bool {
+        self.contact(rhs).is_some()
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// half
+fn half<V: Float>() -> V {
+    V::from(0.5).unwrap()
+}
+// ----------------------------------------------------------------------------
+/// mid
+fn mid<V: Float>(a: &Vector3<V>, b: &Vector3<V>) -> Vector3<V> {
+    (*a + *b) * half::<V>()
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// spheres
+fn spheres<V: Float>(
+    ca: &Vector3<V>,
+    ra: V,
+    cb: &Vector3<V>,
+    rb: V,
+) -> Option<Contact<V>> {
+    let d = *cb - *ca;
+    let l2 = d.length2();
+    let r = ra + rb;
+    if r * r < l2 {
+        return None;
+    }
+    let l = l2.sqrt();
+    let n = if l < V::epsilon() {
+        Vector3::new(V::zero(), V::zero(), V::one())
+    } else {
+        d / l
+    };
+    let (pa, pb) = (*ca + n * ra, *cb - n * rb);
+    Some(Contact::new(n, r - l, mid(&pa, &pb)))
+}
+// ----------------------------------------------------------------------------
+/// sphere_obb
+fn sphere_obb<V: Float>(
+    c: &Vector3<V>,
+    r: V,
+    b: &Obb<V>,
+) -> Option<Contact<V>> {
+    let p = b.to_local(c);
+    let q = p.clamp(&(b.radius * -V::one()), &b.radius);
+    if q != p {
+        let d = q - p;
+        let l2 = d.length2();
+        if r * r < l2 {
+            return None;
+        }
+        let l = l2.sqrt();
+        let n = b.rotate.rotate(&(d / l));
+        let (pa, pb) = (*c + n * r, b.to_world(&q));
+        return Some(Contact::new(n, r - l, mid(&pa, &pb)));
+    }
+    // the center is inside, out through the nearest face
+    let mut i = 0;
+    for k in 1..3 {
+        if b.radius[k] - p[k].abs() < b.radius[i] - p[i].abs() {
+            i = k;
+        }
+    }
+    let gap = b.radius[i] - p[i].abs();
+    let mut out = Vector3::default();
+    out[i] = if p[i] < V::zero() {
+        -V::one()
+    } else {
+        V::one()
+    };
+    let out = b.rotate.rotate(&out);
+    let (pa, pb) = (*c - out * r, *c + out * gap);
+    Some(Contact::new(out * -V::one(), r + gap, mid(&pa, &pb)))
+}
+// ----------------------------------------------------------------------------
+/// sphere_plane
+fn sphere_plane<V: Float>(
+    c: &Vector3<V>,
+    r: V,
+    p: &Plane<V>,
+) -> Option<Contact<V>> {
+    let n = *p.as_normal();
+    let d = p.signed_distance(c);
+    if r < d {
+        return None;
+    }
+    let (pa, pb) = (*c - n * r, *c - n * d);
+    Some(Contact::new(n * -V::one(), r - d, mid(&pa, &pb)))
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// sat_axis
+///
+/// The overlap of the projections onto the unit `l`, and `l` turned from `a`
+/// toward `b`. `None` if it separates them.
+fn sat_axis<V: Float>(
+    l: &Vector3<V>,
+    a: &Obb<V>,
+    ua: &[Vector3<V>; 3],
+    b: &Obb<V>,
+    ub: &[Vector3<V>; 3],
+) -> Option<(V, Vector3<V>)> {
+    let project = |u: &[Vector3<V>; 3], r: &Vector3<V>| {
+        (0..3).fold(V::zero(), |s, k| s + r[k] * u[k].dot(l).abs())
+    };
+    let d = (b.center - a.center).dot(l);
+    let overlap = project(ua, &a.radius) + project(ub, &b.radius) - d.abs();
+    if overlap < V::zero() {
+        None
+    } else if d < V::zero() {
+        Some((overlap, *l * -V::one()))
+    } else {
+        Some((overlap, *l))
+    }
+}
+// ----------------------------------------------------------------------------
+/// clip
+///
+/// Sutherland-Hodgman, keeps `m . p <= offset`.
+fn clip<V: Float>(
+    polygon: &[Vector3<V>],
+    m: &Vector3<V>,
+    offset: V,
+) -> Vec<Vector3<V>> {
+    let mut ret = Vec::with_capacity(polygon.len() + 1);
+    for (i, p) in polygon.iter().enumerate() {
+        let q = polygon[(i + 1) % polygon.len()];
+        let (dp, dq) = (m.dot(p) - offset, m.dot(&q) - offset);
+        if dp <= V::zero() {
+            ret.push(*p);
+        }
+        if (dp < V::zero() && V::zero() < dq)
+            || (V::zero() < dp && dq < V::zero())
+        {
+            ret.push(*p + (q - *p) * (dp / (dp - dq)));
+        }
+    }
+    ret
+}
+// ----------------------------------------------------------------------------
+/// face_contact
+///
+/// The face `i` of `r` turned to `n` is the reference, the face of `inc`
+/// most against it is clipped by its sides.
+fn face_contact<V: Float>(
+    r: &Obb<V>,
+    ur: &[Vector3<V>; 3],
+    i: usize,
+    n: &Vector3<V>,
+    inc: &Obb<V>,
+    ui: &[Vector3<V>; 3],
+) -> Vec<ContactPoint<V>> {
+    let mut k = 0;
+    for j in 1..3 {
+        if ui[k].dot(n).abs() < ui[j].dot(n).abs() {
+            k = j;
+        }
+    }
+    let s = if V::zero() < ui[k].dot(n) {
+        -V::one()
+    } else {
+        V::one()
+    };
+    let fc = inc.center + ui[k] * (s * inc.radius[k]);
+    let e1 = ui[(k + 1) % 3] * inc.radius[(k + 1) % 3];
+    let e2 = ui[(k + 2) % 3] * inc.radius[(k + 2) % 3];
+    let face = [fc + e1 + e2, fc - e1 + e2, fc - e1 - e2, fc + e1 - e2];
+    let mut polygon = face.to_vec();
+    for j in &[(i + 1) % 3, (i + 2) % 3] {
+        let c = ur[*j].dot(&r.center);
+        polygon = clip(&polygon, &ur[*j], c + r.radius[*j]);
+        polygon = clip(&polygon, &(ur[*j] * -V::one()), r.radius[*j] - c);
+    }
+    let offset = n.dot(&r.center) + r.radius[i];
+    let point = |p: &Vector3<V>| {
+        let sep = n.dot(p) - offset;
+        ContactPoint {
+            point: *p - *n * (sep * half::<V>()),
+            depth: -sep,
+        }
+    };
+    let ret: Vec<_> = polygon
+        .iter()
+        .map(point)
+        .filter(|c| V::zero() <= c.depth)
+        .collect();
+    if ret.is_empty() {
+        // lost by the rounding, the deepest corner
+        let deepest = face.iter().map(point).fold(
+            None,
+            |m: Option<ContactPoint<V>>, c| match m {
+                Some(m) if c.depth <= m.depth => Some(m),
+                _ => Some(c),
+            },
+        );
+        deepest.into_iter().collect()
+    } else {
+        ret
+    }
+}
+// ----------------------------------------------------------------------------
+/// obb_obb
+///
+/// The separating axis theorem on the 15 axes. A face axis is preferred
+/// unless an edge axis is clearly shallower, to keep the manifold stable.
+fn obb_obb<V: Float>(a: &Obb<V>, b: &Obb<V>) -> Option<Contact<V>> {
+    let (ua, ub) = (a.axes(), b.axes());
+    let mut best: Option<(V, Vector3<V>, usize, usize)> = None;
+    let mut take = |(o, n): (V, Vector3<V>), i: usize, j: usize| match best {
+        Some((m, _, _, _)) if m <= o => {}
+        _ => best = Some((o, n, i, j)),
+    };
+    // (i, 3) is the face i of a, (3, j) the face j of b
+    for i in 0..3 {
+        take(sat_axis(&ua[i], a, &ua, b, &ub)?, i, 3);
+    }
+    for j in 0..3 {
+        take(sat_axis(&ub[j], a, &ua, b, &ub)?, 3, j);
+    }
+    let (face, ..) = best.unwrap();
+    let mut edge = face * V::from(0.95).unwrap();
+    let mut best_edge: Option<(V, Vector3<V>, usize, usize)> = None;
+    for i in 0..3 {
+        for j in 0..3 {
+            let mut l = ua[i].cross(&ub[j]);
+            if l.length() < V::epsilon().sqrt() {
+                continue;
+            }
+            let (o, n) = sat_axis(l.normalize(), a, &ua, b, &ub)?;
+            if o < edge {
+                edge = o;
+                best_edge = Some((o, n, i, j));
+            }
+        }
+    }
+    let (depth, n, i, j) = best_edge.or(best).unwrap();
+    let points = if j == 3 {
+        face_contact(a, &ua, i, &n, b, &ub)
+    } else if i == 3 {
+        face_contact(b, &ub, j, &(n * -V::one()), a, &ua)
+    } else {
+        let sign = |x: V| if x < V::zero() { -V::one() } else { V::one() };
+        let mut pa = a.center;
+        let mut pb = b.center;
+        for k in 0..3 {
+            if k != i {
+                pa += ua[k] * (a.radius[k] * sign(ua[k].dot(&n)));
+            }
+            if k != j {
+                pb -= ub[k] * (b.radius[k] * sign(ub[k].dot(&n)));
+            }
+        }
+        let (ea, eb) = (ua[i] * a.radius[i], ub[j] * b.radius[j]);
+        let (ca, cb) = closest_segment_segment(
+            &(pa - ea),
+            &(pa + ea),
+            &(pb - eb),
+            &(pb + eb),
+        );
+        vec![ContactPoint {
+            point: mid(&ca, &cb),
+            depth,
+        }]
+    };
+    Some(Contact {
+        normal: n,
+        depth,
+        points,
+    })
+}
+// ----------------------------------------------------------------------------
+/// capsule_obb
+///
+/// The signed distance to a box is convex along the axis of the capsule,
+/// its minimum is found by the golden section search. The ends are added
+/// when they touch the same face.
+fn capsule_obb<V: Float>(c: &Capsule<V>, b: &Obb<V>) -> Option<Contact<V>> {
+    let axis = c.end - c.start;
+    let at = |t: V| c.start + axis * t;
+    let f = |t: V| b.signed_distance(&at(t));
+    let g = V::from(0.618_033_988_749_894_9).unwrap();
+    let (mut lo, mut hi) = (V::zero(), V::one());
+    for _ in 0..48 {
+        let (t0, t1) = (hi - (hi - lo) * g, lo + (hi - lo) * g);
+        if f(t0) < f(t1) {
+            hi = t1;
+        } else {
+            lo = t0;
+        }
+    }
+    let mut ret = sphere_obb(&at((lo + hi) * half::<V>()), c.radius, b)?;
+    let same = V::one() - V::epsilon().sqrt();
+    for end in &[c.start, c.end] {
+        if let Some(e) = sphere_obb(end, c.radius, b) {
+            let p = e.points[0];
+            if same < e.normal.dot(&ret.normal)
+                && ret.points.iter().all(|q| {
+                    V::epsilon().sqrt() < (q.point - p.point).length()
+                })
+            {
+                ret.points.push(p);
+            }
+        }
+    }
+    Some(ret)
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+impl<V> Collide<V> for Sphere<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Self) -> Option<Contact<V>> {
+        spheres(&self.center, self.radius, &rhs.center, rhs.radius)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Collide<V, Capsule<V>> for Sphere<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Capsule<V>) -> Option<Contact<V>> {
+        let q = closest_point_segment(&self.center, &rhs.start, &rhs.end);
+        spheres(&self.center, self.radius, &q, rhs.radius)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Collide<V, Obb<V>> for Sphere<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Obb<V>) -> Option<Contact<V>> {
+        sphere_obb(&self.center, self.radius, rhs)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Collide<V, Plane<V>> for Sphere<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Plane<V>) -> Option<Contact<V>> {
+        sphere_plane(&self.center, self.radius, rhs)
+    }
+}
+// ============================================================================
+impl<V> Collide<V> for Capsule<V>
+where
+    V: Float,
+{
+    /// Two points while the axes are parallel and overlap along them.
+    fn contact(&self, rhs: &Self) -> Option<Contact<V>> {
+        let (a, b) = closest_segment_segment(
+            &self.start,
+            &self.end,
+            &rhs.start,
+            &rhs.end,
+        );
+        let mut ret = spheres(&a, self.radius, &b, rhs.radius)?;
+        let (d0, d1) = (self.end - self.start, rhs.end - rhs.start);
+        let l2 = d0.length2();
+        if V::epsilon() < l2
+            && d0.cross(&d1).length2()
+                <= V::epsilon().sqrt() * l2 * d1.length2()
+        {
+            let t0 = (rhs.start - self.start).dot(&d0) / l2;
+            let t1 = (rhs.end - self.start).dot(&d0) / l2;
+            let lo = t0.min(t1).max(V::zero());
+            let hi = t0.max(t1).min(V::one());
+            if lo < hi {
+                let points: Vec<_> = [lo, hi]
+                    .iter()
+                    .filter_map(|t| {
+                        let a = self.start + d0 * *t;
+                        let b =
+                            closest_point_segment(&a, &rhs.start, &rhs.end);
+                        spheres(&a, self.radius, &b, rhs.radius)
+                    })
+                    .map(|c| c.points[0])
+                    .collect();
+                if points.len() == 2 {
+                    ret.points = points;
+                }
+            }
+        }
+        Some(ret)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Collide<V, Obb<V>> for Capsule<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Obb<V>) -> Option<Contact<V>> {
+        capsule_obb(self, rhs)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Collide<V, Plane<V>> for Capsule<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Plane<V>) -> Option<Contact<V>> {
+        let src = [self.start, self.end]
+            .iter()
+            .filter_map(|c| sphere_plane(c, self.radius, rhs))
+            .collect();
+        Contact::merge(*rhs.as_normal() * -V::one(), src)
+    }
+}
+// ============================================================================
+impl<V> Collide<V> for Obb<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Self) -> Option<Contact<V>> {
+        obb_obb(self, rhs)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Collide<V, Plane<V>> for Obb<V>
+where
+    V: Float,
+{
+    fn contact(&self, rhs: &Plane<V>) -> Option<Contact<V>> {
+        let n = *rhs.as_normal();
+        let src = self
+            .corners()
+            .iter()
+            .filter_map(|c| {
+                let d = rhs.signed_distance(c);
+                if V::zero() < d {
+                    None
+                } else {
+                    Some(Contact::new(n, -d, *c - n * (d * half::<V>())))
+                }
+            })
+            .collect();
+        Contact::merge(n * -V::one(), src)
+    }
+}
+// ============================================================================
+impl<V> Collide<V> for Plane<V>
+where
+    V: Float,
+{
+    /// Two half-spaces have no bounded contact, always `None`.
+    fn contact(&self, _: &Self) -> Option<Contact<V>> {
+        None
+    }
+    /// Apart only if the normals are opposite and the half-spaces disjoint.
+    fn overlap(&self, rhs: &Self) -> bool {
+        let d = self.as_normal().dot(rhs.as_normal());
+        -V::one() + V::epsilon().sqrt() < d
+            || -*rhs.as_distance() <= *self.as_distance()
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// collide_flip!
+macro_rules! collide_flip {
+    ($($a:ident, $b:ident);* $(;)*) => {
+        $(
+            impl<V> Collide<V, $b<V>> for $a<V>
+            where
+                V: Float,
+            {
+                fn contact(&self, rhs: &$b<V>) -> Option<Contact<V>> {
+                    rhs.contact(self).map(Contact::flip)
+                }
+            }
+        )*
+    };
+}
+// ----------------------------------------------------------------------------
+collide_flip! {
+    Capsule, Sphere;
+    Obb, Sphere;
+    Plane, Sphere;
+    Obb, Capsule;
+    Plane, Capsule;
+    Plane, Obb;
+}
+// ============================================================================
+/// collide_obb!
+///
+/// `Cube` and `Cuboid` are taken as `Obb`.
+macro_rules! collide_obb {
+    ($a:ident; $($b:ident),*) => {
+        $(
+            impl<V> Collide<V, $b<V>> for $a<V>
+            where
+                V: Float,
+            {
+                fn contact(&self, rhs: &$b<V>) -> Option<Contact<V>> {
+                    Obb::from(self.clone()).contact(rhs)
+                }
+            }
+            // ----------------------------------------------------------------
+            impl<V> Collide<V, $a<V>> for $b<V>
+            where
+                V: Float,
+            {
+                fn contact(&self, rhs: &$a<V>) -> Option<Contact<V>> {
+                    self.contact(&Obb::from(rhs.clone()))
+                }
+            }
+        )*
+    };
+    ($($a:ident, $b:ident);* $(;)*) => {
+        $(
+            impl<V> Collide<V, $b<V>> for $a<V>
+            where
+                V: Float,
+            {
+                fn contact(&self, rhs: &$b<V>) -> Option<Contact<V>> {
+                    obb_obb(&Obb::from(self.clone()), &Obb::from(rhs.clone()))
+                }
+            }
+        )*
+    };
+}
+// ----------------------------------------------------------------------------
+collide_obb!(Cube; Sphere, Capsule, Obb, Plane);
+collide_obb!(Cuboid; Sphere, Capsule, Obb, Plane);
+collide_obb! {
+    Cube, Cube;
+    Cube, Cuboid;
+    Cuboid, Cube;
+    Cuboid, Cuboid;
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::test_util::{rot, v, Rand};
+    // ========================================================================
+    fn check(c: &Contact<f64>, normal: Vector3<f64>, depth: f64, n: usize) {
+        assert!((c.normal - normal).length() < 1.0e-9, "{:?}", c);
+        assert!((c.depth - depth).abs() < 1.0e-9, "{:?}", c);
+        assert_eq!(c.points.len(), n, "{:?}", c);
+    }
+    // ------------------------------------------------------------------------
+    fn ground() -> Plane<f64> {
+        Plane::new(&mut v(0.0, 0.0, 1.0), 0.0)
+    }
+    // ========================================================================
+    #[test]
+    fn sphere() {
+        let a = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(v(1.5, 0.0, 0.0), 1.0);
+        let c = a.contact(&b).unwrap();
+        check(&c, v(1.0, 0.0, 0.0), 0.5, 1);
+        assert!((c.points[0].point - v(0.75, 0.0, 0.0)).length() < 1.0e-9);
+        check(&b.contact(&a).unwrap(), v(-1.0, 0.0, 0.0), 0.5, 1);
+        assert!(!a.overlap(&Sphere::new(v(0.0, 2.1, 0.0), 1.0)));
+
+        let cap = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 4.0), 1.5);
+        let s = Sphere::new(v(0.0, 2.0, 1.0), 1.0);
+        check(&s.contact(&cap).unwrap(), v(0.0, -1.0, 0.0), 0.5, 1);
+        check(&cap.contact(&s).unwrap(), v(0.0, 1.0, 0.0), 0.5, 1);
+
+        let s = Sphere::new(v(0.0, 0.0, 0.5), 1.0);
+        check(&s.contact(&ground()).unwrap(), v(0.0, 0.0, -1.0), 0.5, 1);
+        check(&ground().contact(&s).unwrap(), v(0.0, 0.0, 1.0), 0.5, 1);
+        assert!(!Sphere::new(v(0.0, 0.0, 1.5), 1.0).overlap(&ground()));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn sphere_box() {
+        let b = Obb::new(
+            v(0.0, 0.0, 0.0),
+            v(1.0, 1.0, 1.0),
+            rot(v(0.0, 0.0, 1.0), 0.25 * ::std::f64::consts::PI),
+        );
+        let s = Sphere::new(v(1.6, 0.0, 0.0), 0.3);
+        check(
+            &s.contact(&b).unwrap(),
+            v(-1.0, 0.0, 0.0),
+            0.3 - (1.6 - 2.0f64.sqrt()),
+            1,
+        );
+        // the center inside
+        let c = Cuboid::new(v(0.0, 0.0, 0.0), v(1.0, 2.0, 3.0));
+        let s = Sphere::new(v(0.8, 0.0, 0.0), 0.5);
+        check(&s.contact(&c).unwrap(), v(-1.0, 0.0, 0.0), 0.7, 1);
+        check(&c.contact(&s).unwrap(), v(1.0, 0.0, 0.0), 0.7, 1);
+        assert!(!Sphere::new(v(2.0, 2.0, 0.0), 1.0)
+            .overlap(&Cube::new(v(0.0, 0.0, 0.0), 1.0)));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn capsule() {
+        let a = Capsule::new(v(-2.0, 0.0, 0.0), v(2.0, 0.0, 0.0), 0.5);
+        let b = Capsule::new(v(0.0, -2.0, 0.8), v(0.0, 2.0, 0.8), 0.5);
+        let c = a.contact(&b).unwrap();
+        check(&c, v(0.0, 0.0, 1.0), 0.2, 1);
+        assert!((c.points[0].point - v(0.0, 0.0, 0.4)).length() < 1.0e-9);
+        // parallel
+        let b = Capsule::new(v(1.0, 0.0, 0.8), v(5.0, 0.0, 0.8), 0.5);
+        check(&a.contact(&b).unwrap(), v(0.0, 0.0, 1.0), 0.2, 2);
+        // lying on the ground and on a box
+        let a = Capsule::new(v(-2.0, 0.0, 0.4), v(2.0, 0.0, 0.4), 0.5);
+        check(&a.contact(&ground()).unwrap(), v(0.0, 0.0, -1.0), 0.1, 2);
+        let b = Cuboid::new(v(0.0, 0.0, -1.0), v(5.0, 5.0, 1.0));
+        let c = a.contact(&b).unwrap();
+        assert!((c.normal - v(0.0, 0.0, -1.0)).length() < 1.0e-6);
+        assert!((c.depth - 0.1).abs() < 1.0e-6);
+        assert!(2 <= c.points.len());
+        // an end on a corner
+        let a = Capsule::new(v(1.2, 1.2, 1.2), v(3.0, 3.0, 3.0), 0.5);
+        let b = Cube::new(v(0.0, 0.0, 0.0), 1.0);
+        let c = a.contact(&b).unwrap();
+        let k = 1.0 / 3.0f64.sqrt();
+        check(&c, v(-k, -k, -k), 0.5 - 0.2 * 3.0f64.sqrt(), 1);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn box_plane() {
+        let b = Cube::new(v(0.0, 0.0, 0.9), 1.0);
+        check(&b.contact(&ground()).unwrap(), v(0.0, 0.0, -1.0), 0.1, 4);
+        check(&ground().contact(&b).unwrap(), v(0.0, 0.0, 1.0), 0.1, 4);
+        let b = Obb::new(
+            v(0.0, 0.0, 1.3),
+            v(1.0, 1.0, 1.0),
+            rot(v(1.0, 0.0, 0.0), 0.25 * ::std::f64::consts::PI),
+        );
+        check(
+            &b.contact(&ground()).unwrap(),
+            v(0.0, 0.0, -1.0),
+            2.0f64.sqrt() - 1.3,
+            2,
+        );
+        let p = Plane::new(&mut v(0.0, 0.0, -1.0), 1.0);
+        assert!(ground().overlap(&p));
+        assert!(!ground().overlap(&Plane::new(&mut v(0.0, 0.0, -1.0), -1.0)));
+        assert!(ground().overlap(&Plane::new(&mut v(1.0, 0.0, 0.0), -5.0)));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn box_box() {
+        // face, resting
+        let a = Cuboid::new(v(0.0, 0.0, 0.0), v(2.0, 2.0, 1.0));
+        let b = Cube::new(v(0.5, 0.0, 1.9), 1.0);
+        let c = a.contact(&b).unwrap();
+        check(&c, v(0.0, 0.0, 1.0), 0.1, 4);
+        for p in &c.points {
+            assert!(
+                (p.depth - 0.1).abs() < 1.0e-9
+                    && (p.point[2] - 0.95).abs() < 1.0e-9
+            );
+        }
+        check(&b.contact(&a).unwrap(), v(0.0, 0.0, -1.0), 0.1, 4);
+        // face, turned
+        let b = Obb::new(
+            v(0.0, 0.0, 1.9),
+            v(1.0, 1.0, 1.0),
+            rot(v(0.0, 0.0, 1.0), 0.25 * ::std::f64::consts::PI),
+        );
+        let a = Cube::new(v(0.0, 0.0, 0.0), 1.0);
+        check(&a.contact(&b).unwrap(), v(0.0, 0.0, 1.0), 0.1, 8);
+        // edge, crossed
+        let q = 0.25 * ::std::f64::consts::PI;
+        let s = 2.0f64.sqrt();
+        let a = Obb::new(
+            v(0.0, 0.0, 0.0),
+            v(1.0, 1.0, 1.0),
+            rot(v(0.0, 1.0, 0.0), q),
+        );
+        let b = Obb::new(
+            v(0.0, 0.0, 2.0 * s - 0.1),
+            v(1.0, 1.0, 1.0),
+            rot(v(1.0, 0.0, 0.0), q),
+        );
+        let c = a.contact(&b).unwrap();
+        check(&c, v(0.0, 0.0, 1.0), 0.1, 1);
+        assert!((c.points[0].point - v(0.0, 0.0, s - 0.05)).length() < 1.0e-9);
+        let b = Obb::new(
+            v(0.0, 0.0, 2.0 * s + 0.1),
+            v(1.0, 1.0, 1.0),
+            rot(v(1.0, 0.0, 0.0), q),
+        );
+        assert!(!a.overlap(&b));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn box_box_random() {
+        let mut rand = Rand::new(0x2545_f491_4f6c_dd1d);
+        let inside =
+            |b: &Obb<f64>, p: &Vector3<f64>| b.signed_distance(p) < 0.0;
+        let mut hits = 0;
+        for _ in 0..300 {
+            let a = rand.obb();
+            let mut b = rand.obb();
+            // a point of a inside b means an overlap
+            let mut shared = false;
+            for _ in 0..200 {
+                let l = v(
+                    2.0 * rand.next() - 1.0,
+                    2.0 * rand.next() - 1.0,
+                    2.0 * rand.next() - 1.0,
+                );
+                if inside(&b, &a.to_world(&a.radius.mul_elem(&l))) {
+                    shared = true;
+                }
+            }
+            match a.contact(&b) {
+                None => assert!(!shared),
+                Some(c) => {
+                    hits += 1;
+                    assert!(!c.points.is_empty());
+                    for p in &c.points {
+                        assert!(
+                            -1.0e-9 <= p.depth && p.depth <= c.depth + 1.0e-9
+                        );
+                    }
+                    // moved by the depth along the normal, they part
+                    b.center += c.normal * (c.depth + 1.0e-9);
+                    assert!(!a.overlap(&b), "{:?}", c);
+                }
+            }
+        }
+        assert!(50 < hits);
+    }
+}
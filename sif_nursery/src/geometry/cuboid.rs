@@ -6,11 +6,11 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/19
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
-use sif_math::{Float, Vector3};
+use sif_math::{ApproxEq, Float, Vector3};
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// struct Cuboid
@@ -54,12 +54,12 @@ where
     // ========================================================================
     /// is_cube
     pub fn is_cube(&self) -> Option<V> {
-        if V::epsilon() < V::abs(self.radius[0] - self.radius[1])
-            || V::epsilon() < V::abs(self.radius[0] - self.radius[2])
+        if self.radius[0].approx_eq(&self.radius[1])
+            && self.radius[0].approx_eq(&self.radius[2])
         {
-            None
-        } else {
             Some(self.radius[0])
+        } else {
+            None
         }
     }
 }
@@ -0,0 +1,389 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! frustum.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Matrix4x4, Vector3, Vector4};
+// ----------------------------------------------------------------------------
+use super::{Capsule, Cuboid, Plane, Sphere};
+use crate::Result;
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// enum Containment
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// Inside
+    Inside,
+    /// Intersect
+    Intersect,
+    /// Outside
+    Outside,
+}
+// ============================================================================
+/// enum ClipDepth
+///
+/// The range of the clip space `z` that a projection maps the near and the
+/// far planes onto.
+#[derive(
+    Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash,
+)]
+pub enum ClipDepth {
+    /// `-w` to `w`, as OpenGL
+    #[default]
+    NegativeOneToOne,
+    /// `0` to `w`, as Direct3D and Vulkan
+    ZeroToOne,
+    /// `w` to `0`, the reversed Z
+    OneToZero,
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Frustum
+///
+/// The planes are in the order left, right, bottom, top, near and far, with
+/// the normals pointing inward. The bits `0`, `1` and `2` of the index of a
+/// corner select right, top and far.
+///
+/// With the far plane at infinity every point is in front of it, and the far
+/// corners are the unit directions of the side edges instead.
+#[derive(Debug, Clone)]
+pub struct Frustum<V>
+where
+    V: Float,
+{
+    /// planes
+    planes: [Plane<V>; 6],
+    /// corners
+    corners: [Vector3<V>; 8],
+    /// infinite
+    infinite: bool,
+}
+// ============================================================================
+impl<V> Frustum<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// from_matrix
+    ///
+    /// From a view-projection matrix onto the clip space whose `z` is in the
+    /// range of `depth`. The far plane of an infinite projection is at
+    /// infinity.
+    /// `Err` if `m` is singular.
+    pub fn from_matrix(m: &Matrix4x4<V>, depth: ClipDepth) -> Result<Self> {
+        // m[col][row]
+        let row = |i: usize| Vector4::new(m[0][i], m[1][i], m[2][i], m[3][i]);
+        let w = row(3);
+        let plane = |v: Vector4<V>| {
+            let mut n = Vector3::from(v);
+            let l = n.length();
+            Plane::new(&mut n, -v[3] / l)
+        };
+        let (near, far, z_near, z_far) = match depth {
+            ClipDepth::NegativeOneToOne => {
+                (w + row(2), w - row(2), -V::one(), V::one())
+            }
+            ClipDepth::ZeroToOne => (row(2), w - row(2), V::zero(), V::one()),
+            ClipDepth::OneToZero => (w - row(2), row(2), V::one(), V::zero()),
+        };
+        let near = plane(near);
+        // `0 <= far[3]` with no normal
+        let infinite = Vector3::from(far).length() <= V::epsilon() * far[3];
+        let far = if infinite {
+            Plane::new(&mut (*near.as_normal() * -V::one()), -V::infinity())
+        } else {
+            plane(far)
+        };
+        let inverse = m.new_inverse()?;
+        let mut corners = [Vector3::default(); 8];
+        for (i, c) in corners.iter_mut().enumerate() {
+            let sign = |bit: usize| {
+                if i & bit == 0 {
+                    -V::one()
+                } else {
+                    V::one()
+                }
+            };
+            let z = if i & 4 == 0 { z_near } else { z_far };
+            let p = inverse * Vector4::new(sign(1), sign(2), z, V::one());
+            *c = Vector3::from(p) / p[3];
+            if infinite && i & 4 != 0 {
+                // a point at infinity, `p[3]` is zero
+                let d = Vector3::from(p);
+                *c = d / d.length();
+                if c.dot(near.as_normal()) < V::zero() {
+                    *c *= -V::one();
+                }
+            }
+        }
+        Ok(Frustum {
+            planes: [
+                plane(w + row(0)),
+                plane(w - row(0)),
+                plane(w + row(1)),
+                plane(w - row(1)),
+                near,
+                far,
+            ],
+            corners,
+            infinite,
+        })
+    }
+    // ========================================================================
+    /// planes
+    pub fn planes(&self) -> &[Plane<V>; 6] {
+        &self.planes
+    }
+    // ------------------------------------------------------------------------
+    /// corners
+    ///
+    /// The far ones are directions if `is_infinite`.
+    pub fn corners(&self) -> &[Vector3<V>; 8] {
+        &self.corners
+    }
+    // ------------------------------------------------------------------------
+    /// is_infinite
+    ///
+    /// Whether the far plane is at infinity.
+    pub fn is_infinite(&self) -> bool {
+        self.infinite
+    }
+    // ------------------------------------------------------------------------
+    /// truncated
+    ///
+    /// The corners, with the far ones at the depth `depth` from the near
+    /// plane if `is_infinite`.
+    pub(crate) fn truncated(&self, depth: V) -> [Vector3<V>; 8] {
+        let mut ret = self.corners;
+        if self.infinite {
+            let n = self.planes[4].as_normal();
+            for i in 0..4 {
+                let d = ret[i + 4];
+                ret[i + 4] = ret[i] + d * (depth / d.dot(n));
+            }
+        }
+        ret
+    }
+    // ------------------------------------------------------------------------
+    /// nearest_plane
+    ///
+    /// The index and the signed distance of the plane with the least signed
+    /// distance to `p`.
+    pub(crate) fn nearest_plane(&self, p: &Vector3<V>) -> (usize, V) {
+        let mut ret = (0, self.planes[0].signed_distance(p));
+        for (i, plane) in self.planes.iter().enumerate().skip(1) {
+            let d = plane.signed_distance(p);
+            if d < ret.1 {
+                ret = (i, d);
+            }
+        }
+        ret
+    }
+    // ========================================================================
+    /// classify
+    ///
+    /// `f` gives the range of the signed distance of a shape to a plane.
+    fn classify(&self, f: impl Fn(&Plane<V>) -> (V, V)) -> Containment {
+        let mut ret = Containment::Inside;
+        for p in &self.planes {
+            let (min, max) = f(p);
+            if max < V::zero() {
+                return Containment::Outside;
+            }
+            if min < V::zero() {
+                ret = Containment::Intersect;
+            }
+        }
+        ret
+    }
+    // ========================================================================
+    /// test_point
+    ///
+    /// `Inside` or `Outside`, a point on a plane is `Inside`.
+    pub fn test_point(&self, p: &Vector3<V>) -> Containment {
+        self.classify(|plane| {
+            let d = plane.signed_distance(p);
+            (d, d)
+        })
+    }
+    // ------------------------------------------------------------------------
+    /// test_sphere
+    ///
+    /// Conservative, a shape near a corner or an edge outside may be
+    /// `Intersect`, but never `Outside` while it overlaps.
+    pub fn test_sphere(&self, s: &Sphere<V>) -> Containment {
+        self.classify(|plane| {
+            let d = plane.signed_distance(&s.center);
+            (d - s.radius, d + s.radius)
+        })
+    }
+    // ------------------------------------------------------------------------
+    /// test_cuboid
+    ///
+    /// Conservative as `test_sphere`.
+    pub fn test_cuboid(&self, c: &Cuboid<V>) -> Containment {
+        self.classify(|plane| {
+            let d = plane.signed_distance(&c.center);
+            let r = plane.as_normal().abs().dot(&c.radius);
+            (d - r, d + r)
+        })
+    }
+    // ------------------------------------------------------------------------
+    /// test_capsule
+    ///
+    /// Conservative as `test_sphere`.
+    pub fn test_capsule(&self, c: &Capsule<V>) -> Containment {
+        self.classify(|plane| {
+            let d0 = plane.signed_distance(&c.start);
+            let d1 = plane.signed_distance(&c.end);
+            (d0.min(d1) - c.radius, d0.max(d1) + c.radius)
+        })
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::super::{Distance, Support};
+    use super::*;
+    // ========================================================================
+    /// looking down `-Z` from `(0, 0, 10)`, near `1`, far `21`, 90 degrees
+    fn frustum() -> Frustum<f64> {
+        let proj = Matrix4x4::new_frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 21.0);
+        let mut view = Matrix4x4::new_identity();
+        view[3] = Vector4::new(0.0, 0.0, -10.0, 1.0);
+        Frustum::from_matrix(&(proj * view), ClipDepth::NegativeOneToOne)
+            .unwrap()
+    }
+    // ========================================================================
+    #[test]
+    fn corners() {
+        let f = frustum();
+        let c = f.corners();
+        assert!((c[0] - Vector3::new(-1.0, -1.0, 9.0)).length() < 1.0e-9);
+        assert!((c[7] - Vector3::new(21.0, 21.0, -11.0)).length() < 1.0e-9);
+        for p in f.planes() {
+            for c in c {
+                assert!(
+                    p.signed_distance(c).abs() < 1.0e-9
+                        || 0.0 < p.signed_distance(c)
+                );
+            }
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn point() {
+        let f = frustum();
+        assert_eq!(
+            f.test_point(&Vector3::new(0.0, 0.0, 0.0)),
+            Containment::Inside
+        );
+        assert_eq!(
+            f.test_point(&Vector3::new(0.0, 0.0, 9.5)),
+            Containment::Outside
+        );
+        assert_eq!(
+            f.test_point(&Vector3::new(0.0, 0.0, -12.0)),
+            Containment::Outside
+        );
+        assert_eq!(
+            f.test_point(&Vector3::new(11.0, 0.0, 0.0)),
+            Containment::Outside
+        );
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn shapes() {
+        let f = frustum();
+        let o = Vector3::new(0.0, 0.0, 0.0);
+        assert_eq!(f.test_sphere(&Sphere::new(o, 1.0)), Containment::Inside);
+        let s = Sphere::new(Vector3::new(0.0, 0.0, -11.5), 1.0);
+        assert_eq!(f.test_sphere(&s), Containment::Intersect);
+        let s = Sphere::new(Vector3::new(20.0, 0.0, 0.0), 1.0);
+        assert_eq!(f.test_sphere(&s), Containment::Outside);
+
+        let r = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(f.test_cuboid(&Cuboid::new(o, r)), Containment::Inside);
+        let c = Cuboid::new(Vector3::new(10.0, 0.0, 0.0), r);
+        assert_eq!(f.test_cuboid(&c), Containment::Intersect);
+        let c = Cuboid::new(Vector3::new(0.0, 0.0, 14.0), r);
+        assert_eq!(f.test_cuboid(&c), Containment::Outside);
+
+        let c = Capsule::new(o, Vector3::new(0.0, 0.0, 9.0), 0.5);
+        assert_eq!(f.test_capsule(&c), Containment::Intersect);
+        let c = Capsule::new(o, Vector3::new(0.0, 0.0, 4.0), 0.5);
+        assert_eq!(f.test_capsule(&c), Containment::Inside);
+        let c = Capsule::new(
+            Vector3::new(-20.0, 0.0, 0.0),
+            Vector3::new(-20.0, 0.0, 5.0),
+            0.5,
+        );
+        assert_eq!(f.test_capsule(&c), Containment::Outside);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn reversed_z() {
+        let m = Matrix4x4::new_perspective_reversed_z(1.0f64, 1.0, 1.0, 100.0);
+        let f = Frustum::from_matrix(&m, ClipDepth::OneToZero).unwrap();
+        assert!(!f.is_infinite());
+        let p = |z: f64| f.test_point(&Vector3::new(0.0, 0.0, z));
+        assert_eq!(p(-0.5), Containment::Outside);
+        assert_eq!(p(-50.0), Containment::Inside);
+        assert_eq!(p(-150.0), Containment::Outside);
+        assert!((f.corners()[0][2] + 1.0).abs() < 1.0e-9);
+        assert!((f.corners()[7][2] + 100.0).abs() < 1.0e-9);
+
+        let m =
+            Matrix4x4::new_perspective_reversed_z_infinite(1.0f64, 1.0, 1.0);
+        let f = Frustum::from_matrix(&m, ClipDepth::OneToZero).unwrap();
+        assert!(f.is_infinite());
+        let p = |z: f64| f.test_point(&Vector3::new(0.0, 0.0, z));
+        assert_eq!(p(-0.5), Containment::Outside);
+        assert_eq!(p(-1.0e12), Containment::Inside);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn infinite() {
+        let m = Matrix4x4::new_perspective_infinite(1.0f64, 1.0, 1.0);
+        let f = Frustum::from_matrix(&m, ClipDepth::NegativeOneToOne).unwrap();
+        assert!(f.is_infinite());
+        let far = &f.planes()[5];
+        assert!((far.as_normal().length() - 1.0).abs() < 1.0e-9);
+        assert_eq!(far.signed_distance(&Vector3::default()), f64::INFINITY);
+        for c in f.corners() {
+            assert!(c.iter().all(|x| x.is_finite()));
+        }
+        // the side edges
+        let t = (0.5f64).tan();
+        let d = Vector3::new(t, t, -1.0) / (2.0 * t * t + 1.0).sqrt();
+        assert!((f.corners()[7] - d).length() < 1.0e-9);
+
+        let p = |z: f64| f.test_point(&Vector3::new(0.0, 0.0, z));
+        assert_eq!(p(-0.5), Containment::Outside);
+        assert_eq!(p(-1.0e12), Containment::Inside);
+        let s = Sphere::new(Vector3::new(0.0, 0.0, -1.0e6), 1.0);
+        assert_eq!(f.test_sphere(&s), Containment::Inside);
+        let s = Sphere::new(Vector3::new(1.0e6, 0.0, -1.0), 1.0);
+        assert_eq!(f.test_sphere(&s), Containment::Outside);
+
+        // beside the far end of a side
+        let p = Vector3::new(200.0 * t + 10.0, 0.0, -200.0);
+        let q = f.closest_point(&p);
+        assert!(q.iter().all(|x| x.is_finite()));
+        assert!((f.planes()[1].signed_distance(&q)).abs() < 1.0e-9);
+        assert!(
+            (f.signed_distance(&p) - 10.0 * (0.5f64).cos()).abs() < 1.0e-9
+        );
+        let s = f.support(&Vector3::new(0.0, 0.0, -1.0));
+        assert!(s.iter().all(|x| x.is_finite()) && s[2] < -1.0e60);
+    }
+}
@@ -0,0 +1,539 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! gjk.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Vector3};
+// ----------------------------------------------------------------------------
+use super::{Contact, ContactPoint, Support};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// GJK_ITERATION
+const GJK_ITERATION: usize = 64;
+// ----------------------------------------------------------------------------
+/// EPA_ITERATION
+const EPA_ITERATION: usize = 128;
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Vertex
+///
+/// A point of the Minkowski difference `a - b`, with its sources.
+#[derive(Debug, Clone, Copy)]
+struct Vertex<V>
+where
+    V: Float,
+{
+    /// w
+    w: Vector3<V>,
+    /// a
+    a: Vector3<V>,
+    /// b
+    b: Vector3<V>,
+}
+// ============================================================================
+impl<V> Vertex<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    fn new<A, B>(a: &A, b: &B, d: &Vector3<V>) -> Self
+    where
+        A: Support<V> + ?Sized,
+        B: Support<V> + ?Sized,
+    {
+        let a = a.support(d);
+        let b = b.support(&(*d * -V::one()));
+        Vertex { w: a - b, a, b }
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// project
+///
+/// The weights of the origin projected onto the affine hull of `src`.
+/// `None` if degenerate.
+fn project<V: Float>(src: &[Vector3<V>]) -> Option<Vec<V>> {
+    let m = src.len() - 1;
+    let e: Vec<Vector3<V>> = src[1..].iter().map(|p| *p - src[0]).collect();
+    let mut g = [[V::zero(); 4]; 3];
+    let mut limit = V::zero();
+    for i in 0..m {
+        for j in 0..m {
+            g[i][j] = e[i].dot(&e[j]);
+        }
+        g[i][m] = -e[i].dot(&src[0]);
+        limit = limit.max(g[i][i]);
+    }
+    limit *= V::epsilon().sqrt();
+    // Gaussian elimination with partial pivoting
+    for c in 0..m {
+        let p = (c..m)
+            .max_by(|&x, &y| {
+                g[x][c].abs().partial_cmp(&g[y][c].abs()).unwrap()
+            })
+            .unwrap();
+        if g[p][c].abs() <= limit {
+            return None;
+        }
+        g.swap(c, p);
+        for r in (c + 1)..m {
+            let f = g[r][c] / g[c][c];
+            let pivot = g[c];
+            for (x, y) in g[r].iter_mut().zip(&pivot).skip(c) {
+                *x -= f * *y;
+            }
+        }
+    }
+    let mut mu = [V::zero(); 3];
+    for c in (0..m).rev() {
+        let s = ((c + 1)..m).fold(g[c][m], |s, k| s - g[c][k] * mu[k]);
+        mu[c] = s / g[c][c];
+    }
+    let mut ret = vec![mu[..m].iter().fold(V::one(), |s, x| s - *x)];
+    ret.extend_from_slice(&mu[..m]);
+    Some(ret)
+}
+// ----------------------------------------------------------------------------
+/// nearest
+///
+/// The sub-simplex of `src` holding the point closest to the origin, with
+/// its weights.
+fn nearest<V: Float>(src: &[Vertex<V>]) -> (Vec<Vertex<V>>, Vec<V>) {
+    let mut ret = (vec![src[0]], vec![V::one()]);
+    let mut best = src[0].w.dot(&src[0].w);
+    for mask in 2..(1usize << src.len()) {
+        let s: Vec<Vertex<V>> = (0..src.len())
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| src[i])
+            .collect();
+        let w: Vec<Vector3<V>> = s.iter().map(|x| x.w).collect();
+        let lambda = match project(&w) {
+            Some(ref l) if l.iter().all(|x| *x > V::zero()) => l.clone(),
+            _ => continue,
+        };
+        let v = combine(&w, &lambda);
+        if v.dot(&v) < best {
+            best = v.dot(&v);
+            ret = (s, lambda);
+        }
+    }
+    ret
+}
+// ----------------------------------------------------------------------------
+/// combine
+fn combine<V: Float>(src: &[Vector3<V>], lambda: &[V]) -> Vector3<V> {
+    src.iter()
+        .zip(lambda)
+        .fold(Vector3::default(), |s, (p, l)| s + *p * *l)
+}
+// ----------------------------------------------------------------------------
+/// witness
+///
+/// The points on `a` and on `b` for the weights.
+fn witness<V: Float>(
+    s: &[Vertex<V>],
+    lambda: &[V],
+) -> (Vector3<V>, Vector3<V>) {
+    let a: Vec<Vector3<V>> = s.iter().map(|x| x.a).collect();
+    let b: Vec<Vector3<V>> = s.iter().map(|x| x.b).collect();
+    (combine(&a, lambda), combine(&b, lambda))
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// enum Gjk
+#[derive(Debug)]
+enum Gjk<V>
+where
+    V: Float,
+{
+    /// Apart
+    Apart(Vector3<V>, Vector3<V>),
+    /// Overlap
+    ///
+    /// The last simplex, which holds the origin.
+    Overlap(Vec<Vertex<V>>),
+}
+// ----------------------------------------------------------------------------
+/// gjk
+fn gjk<V, A, B>(a: &A, b: &B) -> Gjk<V>
+where
+    V: Float,
+    A: Support<V> + ?Sized,
+    B: Support<V> + ?Sized,
+{
+    let tolerance = V::epsilon() * V::from(64).unwrap();
+    let first =
+        Vertex::new(a, b, &Vector3::new(V::one(), V::zero(), V::zero()));
+    let mut scale = first.w.dot(&first.w);
+    let mut s = vec![first];
+    let mut lambda = vec![V::one()];
+    for _ in 0..GJK_ITERATION {
+        let v = combine(&s.iter().map(|x| x.w).collect::<Vec<_>>(), &lambda);
+        let vv = v.dot(&v);
+        if vv <= V::epsilon() * scale {
+            return Gjk::Overlap(s);
+        }
+        let n = Vertex::new(a, b, &(v * -V::one()));
+        if vv - v.dot(&n.w) <= tolerance * vv {
+            break;
+        }
+        scale = scale.max(n.w.dot(&n.w));
+        s.push(n);
+        let (ns, nl) = nearest(&s);
+        if ns.len() == 4 {
+            return Gjk::Overlap(ns);
+        }
+        s = ns;
+        lambda = nl;
+    }
+    let (pa, pb) = witness(&s, &lambda);
+    Gjk::Apart(pa, pb)
+}
+// ============================================================================
+/// gjk_distance
+///
+/// The closest points on `a` and on `b`. `None` if they overlap.
+pub fn gjk_distance<V, A, B>(a: &A, b: &B) -> Option<(Vector3<V>, Vector3<V>)>
+where
+    V: Float,
+    A: Support<V> + ?Sized,
+    B: Support<V> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Apart(pa, pb) => Some((pa, pb)),
+        Gjk::Overlap(_) => None,
+    }
+}
+// ----------------------------------------------------------------------------
+/// gjk_intersect
+pub fn gjk_intersect<V, A, B>(a: &A, b: &B) -> bool
+where
+    V: Float,
+    A: Support<V> + ?Sized,
+    B: Support<V> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Apart(..) => false,
+        Gjk::Overlap(_) => true,
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// tetrahedron
+///
+/// Grows the simplex from `gjk` to a tetrahedron holding the origin.
+/// `None` if the Minkowski difference is flat.
+fn tetrahedron<V, A, B>(
+    a: &A,
+    b: &B,
+    mut s: Vec<Vertex<V>>,
+) -> Option<Vec<Vertex<V>>>
+where
+    V: Float,
+    A: Support<V> + ?Sized,
+    B: Support<V> + ?Sized,
+{
+    let o = V::zero();
+    let l = V::one();
+    let axes = [
+        Vector3::new(l, o, o),
+        Vector3::new(o, l, o),
+        Vector3::new(o, o, l),
+    ];
+    let scale = s.iter().fold(V::zero(), |m, x| m.max(x.w.length()));
+    let limit = V::epsilon().sqrt() * scale.max(V::epsilon());
+    let grow = |s: &mut Vec<Vertex<V>>,
+                ds: &[Vector3<V>],
+                off: &dyn Fn(&Vector3<V>) -> V| {
+        for d in ds {
+            for d in &[*d, *d * -V::one()] {
+                let n = Vertex::new(a, b, d);
+                if off(&n.w) > limit {
+                    s.push(n);
+                    return;
+                }
+            }
+        }
+    };
+    if s.len() == 1 {
+        let p = s[0].w;
+        grow(&mut s, &axes, &|w| (*w - p).length());
+    }
+    if s.len() == 2 {
+        let (p, e) = (s[0].w, s[1].w - s[0].w);
+        let e = e / e.length();
+        let ds: Vec<Vector3<V>> = axes.iter().map(|x| e.cross(x)).collect();
+        grow(&mut s, &ds, &|w| (*w - p).cross(&e).length());
+    }
+    if s.len() == 3 {
+        let p = s[0].w;
+        let n = (s[1].w - p).cross(&(s[2].w - p));
+        let n = n / n.length();
+        grow(&mut s, &[n], &|w| (*w - p).dot(&n).abs());
+    }
+    if s.len() == 4 {
+        Some(s)
+    } else {
+        None
+    }
+}
+// ============================================================================
+/// struct Face
+#[derive(Debug, Clone, Copy)]
+struct Face<V>
+where
+    V: Float,
+{
+    /// index
+    index: [usize; 3],
+    /// normal
+    normal: Vector3<V>,
+    /// distance
+    ///
+    /// From the origin, or `max_value` if degenerate.
+    distance: V,
+}
+// ============================================================================
+impl<V> Face<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    ///
+    /// Degenerate if the sine between the edges is below `epsilon`.
+    fn new(vertices: &[Vertex<V>], index: [usize; 3]) -> Self {
+        let [i, j, k] = index;
+        let p = vertices[i].w;
+        let (e1, e2) = (vertices[j].w - p, vertices[k].w - p);
+        let mut normal = e1.cross(&e2);
+        let l = normal.length();
+        let distance = if l <= V::epsilon() * e1.length() * e2.length() {
+            V::max_value()
+        } else {
+            normal /= l;
+            normal.dot(&p)
+        };
+        Face {
+            index,
+            normal,
+            distance,
+        }
+    }
+}
+// ============================================================================
+/// epa
+///
+/// The penetration of `a` and `b`, with one contact point.
+/// `None` if they are apart or the Minkowski difference is flat.
+pub fn epa<V, A, B>(a: &A, b: &B) -> Option<Contact<V>>
+where
+    V: Float,
+    A: Support<V> + ?Sized,
+    B: Support<V> + ?Sized,
+{
+    let mut vertices = match gjk(a, b) {
+        Gjk::Apart(..) => return None,
+        Gjk::Overlap(s) => tetrahedron(a, b, s)?,
+    };
+    let scale = vertices.iter().fold(V::zero(), |m, x| m.max(x.w.length()));
+    let tolerance = V::epsilon().sqrt() * scale;
+    let mut faces: Vec<Face<V>> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .iter()
+        .map(|&[i, j, k]| {
+            let f = Face::new(&vertices, [i, j, k]);
+            // outward
+            let o = 6 - i - j - k;
+            if f.normal.dot(&(vertices[o].w - vertices[i].w)) > V::zero() {
+                Face::new(&vertices, [i, k, j])
+            } else {
+                f
+            }
+        })
+        .collect();
+    let closest = |faces: &[Face<V>]| {
+        *faces
+            .iter()
+            .min_by(|x, y| x.distance.partial_cmp(&y.distance).unwrap())
+            .unwrap()
+    };
+    let mut f = closest(&faces);
+    for _ in 0..EPA_ITERATION {
+        let n = Vertex::new(a, b, &f.normal);
+        if n.w.dot(&f.normal) - f.distance <= tolerance {
+            break;
+        }
+        vertices.push(n);
+        let top = vertices.len() - 1;
+        let mut edges: Vec<[usize; 2]> = Vec::new();
+        faces.retain(|x| {
+            let visible = x.distance == V::max_value()
+                || x.normal.dot(&(n.w - vertices[x.index[0]].w)) > V::zero();
+            if visible {
+                let [i, j, k] = x.index;
+                edges.extend_from_slice(&[[i, j], [j, k], [k, i]]);
+            }
+            !visible
+        });
+        for e in &edges {
+            if !edges.contains(&[e[1], e[0]]) {
+                faces.push(Face::new(&vertices, [e[0], e[1], top]));
+            }
+        }
+        if faces.is_empty() {
+            break;
+        }
+        f = closest(&faces);
+    }
+    let w: Vec<Vector3<V>> = f
+        .index
+        .iter()
+        .map(|i| vertices[*i].w - f.normal * f.distance)
+        .collect();
+    let third = V::one() / V::from(3).unwrap();
+    let lambda = project(&w).unwrap_or_else(|| vec![third; 3]);
+    let s: Vec<Vertex<V>> = f.index.iter().map(|i| vertices[*i]).collect();
+    let (pa, pb) = witness(&s, &lambda);
+    Some(Contact {
+        normal: f.normal,
+        depth: f.distance,
+        points: vec![ContactPoint {
+            point: (pa + pb) * V::from(0.5).unwrap(),
+            depth: f.distance,
+        }],
+    })
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::geometry::{
+        Capsule, Collide, ConvexHull, Cube, Cuboid, Distance, MinkowskiSum,
+        Obb, Segment, Sphere, Transformed,
+    };
+    use crate::test_util::{rot, v, Rand};
+    // ========================================================================
+    #[test]
+    fn distance() {
+        let a = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(v(3.0, 4.0, 0.0), 2.0);
+        let (pa, pb) = gjk_distance(&a, &b).unwrap();
+        assert!((pa - v(0.6, 0.8, 0.0)).length() < 1.0e-6, "{:?}", pa);
+        assert!((pb - v(1.8, 2.4, 0.0)).length() < 1.0e-6, "{:?}", pb);
+        assert!(
+            gjk_distance(&a, &Sphere::new(v(2.5, 0.0, 0.0), 2.0)).is_none()
+        );
+
+        let a = Cube::new(v(0.0, 0.0, 0.0), 1.0);
+        let b = Cuboid::new(v(3.0, 0.5, 4.0), v(0.5, 0.5, 1.0));
+        let (pa, pb) = gjk_distance(&a, &b).unwrap();
+        assert!(((pb - pa).length() - 2.5).abs() < 1.0e-9);
+        assert!((pa - v(1.0, pa[1], 1.0)).length() < 1.0e-9, "{:?}", pa);
+
+        // a segment swept by a sphere is a capsule
+        let seg = Segment::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 4.0));
+        let sum = MinkowskiSum(&seg, Sphere::new(v(0.0, 0.0, 0.0), 1.0));
+        let cap = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 4.0), 1.0);
+        for p in &[v(3.0, 0.0, 2.0), v(1.0, 1.0, 6.0), v(0.0, -2.0, -2.0)] {
+            let dot = Sphere::new(*p, 0.0);
+            let (pa, _) = gjk_distance(&sum, &dot).unwrap();
+            assert!((pa - cap.closest_point(p)).length() < 1.0e-6, "{:?}", p);
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn support() {
+        let r = rot(v(1.0, 2.0, 3.0), 0.7);
+        let c = Cuboid::new(v(0.0, 0.0, 0.0), v(1.0, 2.0, 3.0));
+        let t = Transformed::new(c.clone(), r, v(1.0, -1.0, 2.0));
+        let o = Obb::new(v(1.0, -1.0, 2.0), v(1.0, 2.0, 3.0), r);
+        let hull = ConvexHull::new(o.corners().to_vec()).unwrap();
+        for d in &[v(1.0, 0.0, 0.0), v(-1.0, 2.0, 0.5), v(0.3, -0.2, -1.0)] {
+            assert!((t.support(d) - o.support(d)).length() < 1.0e-9);
+            assert!((hull.support(d) - o.support(d)).length() < 1.0e-9);
+        }
+        assert!(ConvexHull::<f64>::new(Vec::new()).is_err());
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn penetration() {
+        let a = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(v(1.5, 0.0, 0.0), 1.0);
+        let c = epa(&a, &b).unwrap();
+        assert!((c.normal - v(1.0, 0.0, 0.0)).length() < 1.0e-3, "{:?}", c);
+        assert!((c.depth - 0.5).abs() < 1.0e-4, "{:?}", c);
+        assert!((c.points[0].point - v(0.75, 0.0, 0.0)).length() < 1.0e-2);
+
+        let a = Cube::new(v(0.0, 0.0, 0.0), 1.0);
+        let b = Cuboid::new(v(0.5, 0.2, 2.9), v(1.0, 1.0, 2.0));
+        let c = epa(&a, &b).unwrap();
+        assert!((c.normal - v(0.0, 0.0, 1.0)).length() < 1.0e-9, "{:?}", c);
+        assert!((c.depth - 0.1).abs() < 1.0e-9, "{:?}", c);
+        assert!(epa(&a, &Cube::new(v(0.0, 0.0, 2.5), 1.0)).is_none());
+
+        // centimetres in f32
+        let w = |x: f32, y: f32, z: f32| Vector3::new(x, y, z);
+        let a = Cube::new(w(0.0, 0.0, 0.0), 0.005f32);
+        let b = Cube::new(w(0.002, 0.001, 0.009), 0.005);
+        let c = epa(&a, &b).unwrap();
+        assert!((c.normal - w(0.0, 0.0, 1.0)).length() < 1.0e-3, "{:?}", c);
+        assert!((c.depth - 0.001).abs() < 1.0e-5, "{:?}", c);
+        for &r in &[0.05f32, 0.01, 0.005] {
+            let a = Sphere::new(w(0.0, 0.0, 0.0), r);
+            let b = Sphere::new(w(r, 0.0, 0.0), r);
+            let c = epa(&a, &b).unwrap();
+            assert!(
+                (c.normal - w(1.0, 0.0, 0.0)).length() < 5.0e-2,
+                "{:?}",
+                c
+            );
+            assert!((c.depth - r).abs() < r * 1.0e-2, "{} {:?}", r, c);
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn box_box_random() {
+        let mut rand = Rand::new(0x9e37_79b9_7f4a_7c15);
+        let mut hits = 0;
+        for _ in 0..300 {
+            let a = rand.obb();
+            let b = rand.obb();
+            match a.contact(&b) {
+                Some(sat) => {
+                    assert!(gjk_intersect(&a, &b));
+                    let c = epa(&a, &b).unwrap();
+                    // SAT prefers faces by a margin, EPA does not
+                    assert!(
+                        c.depth <= sat.depth + 1.0e-6,
+                        "{:?} {:?}",
+                        c,
+                        sat
+                    );
+                    assert!(c.depth >= sat.depth * 0.95 - 1.0e-6);
+                    // moving b out along the normal parts them
+                    let mut b = b;
+                    b.center += c.normal * (c.depth + 1.0e-3);
+                    assert!(!a.overlap(&b));
+                    hits += 1;
+                }
+                None => {
+                    let (pa, pb) = gjk_distance(&a, &b).unwrap();
+                    let d = (pb - pa).length();
+                    assert!((a.signed_distance(&pb) - d).abs() < 1.0e-6);
+                    assert!((b.signed_distance(&pa) - d).abs() < 1.0e-6);
+                }
+            }
+        }
+        assert!(hits > 30, "{}", hits);
+    }
+}
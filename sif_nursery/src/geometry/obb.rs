@@ -0,0 +1,119 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! obb.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Quaternion, Vector3};
+// ----------------------------------------------------------------------------
+use super::{Cube, Cuboid};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Obb
+///
+/// The oriented `Cuboid`.
+#[derive(Debug, Clone)]
+pub struct Obb<V>
+where
+    V: Float,
+{
+    /// center
+    pub center: Vector3<V>,
+    /// radius
+    pub radius: Vector3<V>,
+    /// rotate
+    pub rotate: Quaternion<V>,
+}
+// ============================================================================
+impl<V> Default for Obb<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    fn default() -> Self {
+        Obb::from(Cuboid::default())
+    }
+}
+// ============================================================================
+impl<V> From<Cuboid<V>> for Obb<V>
+where
+    V: Float,
+{
+    fn from(src: Cuboid<V>) -> Self {
+        Obb::new(src.center, src.radius, Quaternion::default())
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> From<Cube<V>> for Obb<V>
+where
+    V: Float,
+{
+    fn from(src: Cube<V>) -> Self {
+        let r = Vector3::new(src.radius, src.radius, src.radius);
+        Obb::new(src.center, r, Quaternion::default())
+    }
+}
+// ============================================================================
+impl<V> Obb<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    pub fn new(
+        center: Vector3<V>,
+        radius: Vector3<V>,
+        rotate: Quaternion<V>,
+    ) -> Self {
+        Obb {
+            center,
+            radius,
+            rotate,
+        }
+    }
+    // ========================================================================
+    /// axes
+    ///
+    /// The local `X`, `Y` and `Z` in the world.
+    pub fn axes(&self) -> [Vector3<V>; 3] {
+        let (o, l) = (V::zero(), V::one());
+        [
+            self.rotate.rotate(&Vector3::new(l, o, o)),
+            self.rotate.rotate(&Vector3::new(o, l, o)),
+            self.rotate.rotate(&Vector3::new(o, o, l)),
+        ]
+    }
+    // ------------------------------------------------------------------------
+    /// to_local
+    pub fn to_local(&self, p: &Vector3<V>) -> Vector3<V> {
+        self.rotate.conjugate().rotate(&(*p - self.center))
+    }
+    // ------------------------------------------------------------------------
+    /// to_world
+    pub fn to_world(&self, p: &Vector3<V>) -> Vector3<V> {
+        self.center + self.rotate.rotate(p)
+    }
+    // ========================================================================
+    /// corners
+    ///
+    /// The bits `0`, `1` and `2` of the index select `+X`, `+Y` and `+Z`.
+    pub fn corners(&self) -> [Vector3<V>; 8] {
+        let mut ret = [Vector3::default(); 8];
+        for (i, c) in ret.iter_mut().enumerate() {
+            let mut p = self.radius;
+            for k in 0..3 {
+                if i & (1 << k) == 0 {
+                    p[k] = -p[k];
+                }
+            }
+            *c = self.to_world(&p);
+        }
+        ret
+    }
+}
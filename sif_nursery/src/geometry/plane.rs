@@ -6,7 +6,7 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/12
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
@@ -77,6 +77,13 @@ where
         self
     }
     // ========================================================================
+    /// signed_distance
+    ///
+    /// Positive on the side the normal points to.
+    pub fn signed_distance(&self, p: &Vector3<V>) -> V {
+        self.normal.dot(p) - self.distance
+    }
+    // ========================================================================
     /// symmetry
     pub fn symmetry(&self, px: V, py: V, pz: V) -> Vector3<V> {
         let mut r = Vector3::from_no_clean([px, py, pz]);
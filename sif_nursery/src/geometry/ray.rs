@@ -6,11 +6,13 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/03/17
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 use sif_math::{Float, Vector3};
+// ----------------------------------------------------------------------------
+use super::{Hit, RayCast};
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// struct Ray
@@ -51,4 +53,31 @@ where
     pub fn new(start: Vector3<V>, end: Vector3<V>) -> Self {
         Ray::<V> { start, end }
     }
+    // ========================================================================
+    /// direction
+    ///
+    /// Unit, from `start` toward `end`, zero if they coincide.
+    pub fn direction(&self) -> Vector3<V> {
+        let d = self.end - self.start;
+        let l = d.length();
+        if l == V::zero() {
+            Vector3::default()
+        } else {
+            d / l
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// intersect
+    ///
+    /// The first hit beyond `start`, through `end` to the infinity.
+    pub fn intersect<S>(&self, shape: &S) -> Option<Hit<V>>
+    where
+        S: RayCast<V> + ?Sized,
+    {
+        let d = self.direction();
+        if d == Vector3::default() {
+            return None;
+        }
+        shape.ray_cast(&self.start, &d, V::infinity())
+    }
 }
@@ -0,0 +1,486 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! ray_cast.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Vector3};
+// ----------------------------------------------------------------------------
+use super::{Capsule, Cube, Cuboid, Plane, Sphere, Triangle};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Hit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit<V>
+where
+    V: Float,
+{
+    /// distance
+    pub distance: V,
+    /// point
+    pub point: Vector3<V>,
+    /// normal
+    ///
+    /// Unit, against the ray.
+    pub normal: Vector3<V>,
+}
+// ============================================================================
+impl<V> Hit<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    fn new(
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        distance: V,
+        normal: Vector3<V>,
+    ) -> Self {
+        Hit {
+            distance,
+            point: *start + *direction * distance,
+            normal,
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// inside
+    fn inside(start: &Vector3<V>, direction: &Vector3<V>) -> Self {
+        Hit::new(start, direction, V::zero(), *direction * -V::one())
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait RayCast
+pub trait RayCast<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// ray_cast
+    ///
+    /// The first hit of `start + direction * t` in `0 <= t <= max`, where
+    /// `direction` is unit. A `start` inside a solid hits at once, with the
+    /// normal `-direction`.
+    fn ray_cast(
+        &self,
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        max: V,
+    ) -> Option<Hit<V>>;
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// sphere_enter
+///
+/// The `t` entering the sphere, negative if `start` is inside.
+fn sphere_enter<V: Float>(
+    center: &Vector3<V>,
+    radius: V,
+    start: &Vector3<V>,
+    direction: &Vector3<V>,
+) -> Option<V> {
+    let m = *start - *center;
+    let b = m.dot(direction);
+    let c = m.length2() - radius * radius;
+    if V::zero() < c && V::zero() < b {
+        return None;
+    }
+    let d = b * b - c;
+    if d < V::zero() {
+        None
+    } else {
+        Some(-b - d.sqrt())
+    }
+}
+// ----------------------------------------------------------------------------
+/// aabb
+fn aabb<V: Float>(
+    center: &Vector3<V>,
+    radius: &Vector3<V>,
+    start: &Vector3<V>,
+    direction: &Vector3<V>,
+    max: V,
+) -> Option<Hit<V>> {
+    let (mut t_min, mut t_max) = (V::zero(), max);
+    let mut normal = None;
+    for i in 0..3 {
+        let (lo, hi) = (center[i] - radius[i], center[i] + radius[i]);
+        if direction[i].abs() < V::epsilon() {
+            if start[i] < lo || hi < start[i] {
+                return None;
+            }
+            continue;
+        }
+        let t0 = (lo - start[i]) / direction[i];
+        let t1 = (hi - start[i]) / direction[i];
+        let (near, far, sign) = if t0 < t1 {
+            (t0, t1, -V::one())
+        } else {
+            (t1, t0, V::one())
+        };
+        if t_min < near {
+            t_min = near;
+            let mut n = Vector3::default();
+            n[i] = sign;
+            normal = Some(n);
+        }
+        t_max = t_max.min(far);
+        if t_max < t_min {
+            return None;
+        }
+    }
+    Some(match normal {
+        Some(n) => Hit::new(start, direction, t_min, n),
+        None => Hit::inside(start, direction),
+    })
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+impl<V> RayCast<V> for Sphere<V>
+where
+    V: Float,
+{
+    fn ray_cast(
+        &self,
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        max: V,
+    ) -> Option<Hit<V>> {
+        let t = sphere_enter(&self.center, self.radius, start, direction)?;
+        if t < V::zero() {
+            Some(Hit::inside(start, direction))
+        } else if max < t {
+            None
+        } else {
+            let p = *start + *direction * t;
+            Some(Hit::new(
+                start,
+                direction,
+                t,
+                (p - self.center) / self.radius,
+            ))
+        }
+    }
+}
+// ============================================================================
+impl<V> RayCast<V> for Cube<V>
+where
+    V: Float,
+{
+    fn ray_cast(
+        &self,
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        max: V,
+    ) -> Option<Hit<V>> {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        aabb(&self.center, &r, start, direction, max)
+    }
+}
+// ============================================================================
+impl<V> RayCast<V> for Cuboid<V>
+where
+    V: Float,
+{
+    fn ray_cast(
+        &self,
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        max: V,
+    ) -> Option<Hit<V>> {
+        aabb(&self.center, &self.radius, start, direction, max)
+    }
+}
+// ============================================================================
+impl<V> RayCast<V> for Capsule<V>
+where
+    V: Float,
+{
+    /// The first of the entries into the side and the two caps.
+    fn ray_cast(
+        &self,
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        max: V,
+    ) -> Option<Hit<V>> {
+        let axis = self.end - self.start;
+        let l2 = axis.length2();
+        let perp = |v: &Vector3<V>| {
+            if l2 < V::epsilon() {
+                *v
+            } else {
+                *v - axis * (v.dot(&axis) / l2)
+            }
+        };
+        let ao = *start - self.start;
+        let s = if l2 < V::epsilon() {
+            V::zero()
+        } else {
+            (ao.dot(&axis) / l2).max(V::zero()).min(V::one())
+        };
+        if (ao - axis * s).length2() <= self.radius * self.radius {
+            return Some(Hit::inside(start, direction));
+        }
+        let o = perp(&ao);
+        let mut best: Option<Hit<V>> = None;
+        let mut take = |h: Hit<V>| match best {
+            Some(b) if b.distance <= h.distance => {}
+            _ => best = Some(h),
+        };
+        // side
+        let d = perp(direction);
+        let a = d.length2();
+        if V::epsilon() < a {
+            let b = o.dot(&d);
+            let c = o.length2() - self.radius * self.radius;
+            let disc = b * b - a * c;
+            if V::zero() <= disc {
+                let t = (-b - disc.sqrt()) / a;
+                let p = *start + *direction * t;
+                let s = (p - self.start).dot(&axis);
+                if V::zero() <= t && t <= max && V::zero() <= s && s <= l2 {
+                    take(Hit::new(
+                        start,
+                        direction,
+                        t,
+                        (o + d * t) / self.radius,
+                    ));
+                }
+            }
+        }
+        // caps
+        for center in &[self.start, self.end] {
+            if let Some(t) =
+                sphere_enter(center, self.radius, start, direction)
+            {
+                if V::zero() <= t && t <= max {
+                    let p = *start + *direction * t;
+                    take(Hit::new(
+                        start,
+                        direction,
+                        t,
+                        (p - *center) / self.radius,
+                    ));
+                }
+            }
+        }
+        best
+    }
+}
+// ============================================================================
+impl<V> RayCast<V> for Plane<V>
+where
+    V: Float,
+{
+    /// Either side, the plane has no inside.
+    fn ray_cast(
+        &self,
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        max: V,
+    ) -> Option<Hit<V>> {
+        let n = *self.as_normal();
+        let denom = n.dot(direction);
+        if denom.abs() < V::epsilon() {
+            return None;
+        }
+        let t = -self.signed_distance(start) / denom;
+        if t < V::zero() || max < t {
+            return None;
+        }
+        let normal = if denom < V::zero() { n } else { n * -V::one() };
+        Some(Hit::new(start, direction, t, normal))
+    }
+}
+// ============================================================================
+impl<V> RayCast<V> for Triangle<V>
+where
+    V: Float,
+{
+    /// Either side, by Möller-Trumbore.
+    fn ray_cast(
+        &self,
+        start: &Vector3<V>,
+        direction: &Vector3<V>,
+        max: V,
+    ) -> Option<Hit<V>> {
+        let e1 = self.b - self.a;
+        let e2 = self.c - self.a;
+        let p = direction.cross(&e2);
+        let det = e1.dot(&p);
+        if det.abs() <= V::epsilon() * e1.length() * p.length() {
+            return None;
+        }
+        let inv = V::one() / det;
+        let s = *start - self.a;
+        let u = s.dot(&p) * inv;
+        if u < V::zero() || V::one() < u {
+            return None;
+        }
+        let q = s.cross(&e1);
+        let v = direction.dot(&q) * inv;
+        if v < V::zero() || V::one() < u + v {
+            return None;
+        }
+        let t = e2.dot(&q) * inv;
+        if t < V::zero() || max < t {
+            return None;
+        }
+        let n = self.normal();
+        let normal = if n.dot(direction) < V::zero() {
+            n
+        } else {
+            n * -V::one()
+        };
+        Some(Hit::new(start, direction, t, normal))
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::super::{Distance, Ray, Segment};
+    use super::*;
+    use crate::test_util::v;
+    // ========================================================================
+    fn near(
+        h: Option<Hit<f64>>,
+        distance: f64,
+        point: Vector3<f64>,
+        normal: Vector3<f64>,
+    ) {
+        let h = h.unwrap();
+        assert!((h.distance - distance).abs() < 1.0e-9, "{:?}", h);
+        assert!((h.point - point).length() < 1.0e-9, "{:?}", h);
+        assert!((h.normal - normal).length() < 1.0e-9, "{:?}", h);
+    }
+    // ========================================================================
+    #[test]
+    fn sphere() {
+        let s = Sphere::new(v(0.0, 0.0, 5.0), 1.0);
+        let r = Ray::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 1.0));
+        near(r.intersect(&s), 4.0, v(0.0, 0.0, 4.0), v(0.0, 0.0, -1.0));
+        assert_eq!(
+            Segment::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 3.9)).intersect(&s),
+            None
+        );
+        assert_eq!(
+            Ray::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, -1.0)).intersect(&s),
+            None
+        );
+        assert_eq!(
+            Ray::new(v(2.0, 0.0, 0.0), v(2.0, 0.0, 1.0)).intersect(&s),
+            None
+        );
+        let r = Ray::new(v(0.0, 0.0, 5.5), v(1.0, 0.0, 5.5));
+        near(r.intersect(&s), 0.0, v(0.0, 0.0, 5.5), v(-1.0, 0.0, 0.0));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn cuboid() {
+        let c = Cuboid::new(v(0.0, 0.0, 0.0), v(1.0, 2.0, 3.0));
+        let r = Ray::new(v(5.0, 1.0, 1.0), v(4.0, 1.0, 1.0));
+        near(r.intersect(&c), 4.0, v(1.0, 1.0, 1.0), v(1.0, 0.0, 0.0));
+        let r = Ray::new(v(0.5, 5.0, 0.0), v(0.5, 4.0, 0.0));
+        near(r.intersect(&c), 3.0, v(0.5, 2.0, 0.0), v(0.0, 1.0, 0.0));
+        let r = Ray::new(v(5.0, 5.0, 0.0), v(6.0, 5.0, 0.0));
+        assert_eq!(r.intersect(&c), None);
+        let cube = Cube::new(v(0.0, 0.0, 10.0), 1.0);
+        let r = Ray::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 1.0));
+        near(r.intersect(&cube), 9.0, v(0.0, 0.0, 9.0), v(0.0, 0.0, -1.0));
+        let s = Segment::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 8.5));
+        assert_eq!(s.intersect(&cube), None);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn capsule() {
+        let c = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 4.0), 1.0);
+        // the side
+        let r = Ray::new(v(5.0, 0.0, 2.0), v(4.0, 0.0, 2.0));
+        near(r.intersect(&c), 4.0, v(1.0, 0.0, 2.0), v(1.0, 0.0, 0.0));
+        // the caps
+        let r = Ray::new(v(0.0, 0.0, 10.0), v(0.0, 0.0, 9.0));
+        near(r.intersect(&c), 5.0, v(0.0, 0.0, 5.0), v(0.0, 0.0, 1.0));
+        let r = Ray::new(v(0.0, 0.0, -3.0), v(0.0, 0.0, 0.0));
+        near(r.intersect(&c), 2.0, v(0.0, 0.0, -1.0), v(0.0, 0.0, -1.0));
+        assert_eq!(
+            Ray::new(v(2.0, 0.0, 0.0), v(2.0, 1.0, 0.0)).intersect(&c),
+            None
+        );
+        // inside a cap
+        let r = Ray::new(v(0.0, 0.0, -0.5), v(1.0, 0.0, -0.5));
+        near(r.intersect(&c), 0.0, v(0.0, 0.0, -0.5), v(-1.0, 0.0, 0.0));
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn plane_triangle() {
+        let p = Plane::new(&mut v(0.0, 0.0, 2.0), 1.0);
+        let r = Ray::new(v(3.0, 0.0, 5.0), v(3.0, 0.0, 4.0));
+        near(r.intersect(&p), 4.0, v(3.0, 0.0, 1.0), v(0.0, 0.0, 1.0));
+        let r = Ray::new(v(3.0, 0.0, -5.0), v(3.0, 0.0, -4.0));
+        near(r.intersect(&p), 6.0, v(3.0, 0.0, 1.0), v(0.0, 0.0, -1.0));
+        assert_eq!(
+            Ray::new(v(0.0, 0.0, 5.0), v(1.0, 0.0, 5.0)).intersect(&p),
+            None
+        );
+
+        let t = Triangle::new(
+            v(0.0, 0.0, 1.0),
+            v(2.0, 0.0, 1.0),
+            v(0.0, 2.0, 1.0),
+        );
+        let r = Ray::new(v(0.5, 0.5, 5.0), v(0.5, 0.5, 4.0));
+        near(r.intersect(&t), 4.0, v(0.5, 0.5, 1.0), v(0.0, 0.0, 1.0));
+        let r = Ray::new(v(0.5, 0.5, -5.0), v(0.5, 0.5, 0.0));
+        near(r.intersect(&t), 6.0, v(0.5, 0.5, 1.0), v(0.0, 0.0, -1.0));
+        assert_eq!(
+            Ray::new(v(1.5, 1.5, 5.0), v(1.5, 1.5, 4.0)).intersect(&t),
+            None
+        );
+        let s = Segment::new(v(0.5, 0.5, 5.0), v(0.5, 0.5, 2.0));
+        assert_eq!(s.intersect(&t), None);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn small_triangle() {
+        // 1 cm and 1 mm in f32
+        for &d in &[0.01f32, 0.001] {
+            let t = Triangle::new(
+                Vector3::new(0.0f32, 0.0, 0.0),
+                Vector3::new(d, 0.0, 0.0),
+                Vector3::new(0.0, d, 0.0),
+            );
+            assert_eq!(t.normal(), Vector3::new(0.0, 0.0, 1.0));
+            let p = Vector3::new(d * 0.25, d * 0.25, 0.0);
+            let r = Ray::new(p + Vector3::new(0.0, 0.0, 1.0), p);
+            let hit = r.intersect(&t).unwrap();
+            assert!((hit.distance - 1.0).abs() < 1.0e-6);
+            assert!((hit.point - p).length() < 1.0e-6);
+            assert_eq!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn short_ray() {
+        // 0.1 mm toward a sphere 5 m away in f32
+        let o = Vector3::new(0.0f32, 0.0, 0.0);
+        let r = Ray::new(o, Vector3::new(0.0, 0.0, 1.0e-4));
+        assert_eq!(r.direction(), Vector3::new(0.0, 0.0, 1.0));
+        let s = Sphere::new(Vector3::new(0.0, 0.0, 5.0), 1.0);
+        let hit = r.intersect(&s).unwrap();
+        assert!((hit.distance - 4.0).abs() < 1.0e-5);
+        assert_eq!(
+            r.closest_point(&Vector3::new(1.0, 0.0, 2.0)).length(),
+            2.0
+        );
+        assert!(Ray::new(o, o).intersect(&s).is_none());
+    }
+}
@@ -6,11 +6,13 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/03/17
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 use sif_math::{Float, Vector3};
+// ----------------------------------------------------------------------------
+use super::{Hit, RayCast};
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// struct Segment
@@ -51,4 +53,23 @@ where
     pub fn new(start: Vector3<V>, end: Vector3<V>) -> Self {
         Segment::<V> { start, end }
     }
+    // ========================================================================
+    /// length
+    pub fn length(&self) -> V {
+        (self.end - self.start).length()
+    }
+    // ------------------------------------------------------------------------
+    /// intersect
+    ///
+    /// The first hit between `start` and `end`.
+    pub fn intersect<S>(&self, shape: &S) -> Option<Hit<V>>
+    where
+        S: RayCast<V> + ?Sized,
+    {
+        let l = self.length();
+        if l < V::epsilon() {
+            return None;
+        }
+        shape.ray_cast(&self.start, &((self.end - self.start) / l), l)
+    }
 }
@@ -0,0 +1,273 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! support.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Quaternion, Vector3};
+// ----------------------------------------------------------------------------
+use super::{Capsule, Cube, Cuboid, Frustum, Obb, Segment, Sphere, Triangle};
+use crate::{Error, Result};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// trait Support
+///
+/// A bounded convex shape. `Plane` and `Ray` are unbounded and have none.
+pub trait Support<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// support
+    ///
+    /// The farthest point in the direction `d`, which need not be unit.
+    fn support(&self, d: &Vector3<V>) -> Vector3<V>;
+}
+// ============================================================================
+impl<V, T> Support<V> for &T
+where
+    V: Float,
+    T: Support<V> + ?Sized,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        (**self).support(d)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// farthest
+fn farthest<V: Float>(src: &[Vector3<V>], d: &Vector3<V>) -> Vector3<V> {
+    let mut ret = src[0];
+    for p in &src[1..] {
+        if ret.dot(d) < p.dot(d) {
+            ret = *p;
+        }
+    }
+    ret
+}
+// ----------------------------------------------------------------------------
+/// sign
+fn sign<V: Float>(x: V) -> V {
+    if x < V::zero() {
+        -V::one()
+    } else {
+        V::one()
+    }
+}
+// ----------------------------------------------------------------------------
+/// toward
+///
+/// `radius` in the direction `d`, zero if `d` is zero.
+fn toward<V: Float>(d: &Vector3<V>, radius: V) -> Vector3<V> {
+    let l = d.length();
+    if l == V::zero() {
+        Vector3::default()
+    } else {
+        *d * (radius / l)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+impl<V> Support<V> for Sphere<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        self.center + toward(d, self.radius)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Support<V> for Cube<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        let r = self.radius;
+        self.center
+            + Vector3::new(sign(d[0]) * r, sign(d[1]) * r, sign(d[2]) * r)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Support<V> for Cuboid<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        let r = self.radius;
+        self.center
+            + Vector3::new(
+                sign(d[0]) * r[0],
+                sign(d[1]) * r[1],
+                sign(d[2]) * r[2],
+            )
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Support<V> for Obb<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        let l = self.rotate.conjugate().rotate(d);
+        let r = self.radius;
+        self.to_world(&Vector3::new(
+            sign(l[0]) * r[0],
+            sign(l[1]) * r[1],
+            sign(l[2]) * r[2],
+        ))
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Support<V> for Capsule<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        farthest(&[self.start, self.end], d) + toward(d, self.radius)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Support<V> for Segment<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        farthest(&[self.start, self.end], d)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Support<V> for Triangle<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        farthest(&[self.a, self.b, self.c], d)
+    }
+}
+// ----------------------------------------------------------------------------
+impl<V> Support<V> for Frustum<V>
+where
+    V: Float,
+{
+    /// An infinite frustum is truncated at the depth `max_value^(1/4)`,
+    /// which keeps the products of the coordinates finite.
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        farthest(&self.truncated(V::max_value().sqrt().sqrt()), d)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Transformed
+///
+/// `shape` rotated by `rotate`, then moved by `translate`.
+#[derive(Debug, Clone)]
+pub struct Transformed<V, S>
+where
+    V: Float,
+{
+    /// shape
+    pub shape: S,
+    /// rotate
+    pub rotate: Quaternion<V>,
+    /// translate
+    pub translate: Vector3<V>,
+}
+// ============================================================================
+impl<V, S> Transformed<V, S>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    pub fn new(
+        shape: S,
+        rotate: Quaternion<V>,
+        translate: Vector3<V>,
+    ) -> Self {
+        Transformed {
+            shape,
+            rotate,
+            translate,
+        }
+    }
+}
+// ============================================================================
+impl<V, S> Support<V> for Transformed<V, S>
+where
+    V: Float,
+    S: Support<V>,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        let l = self.rotate.conjugate().rotate(d);
+        self.translate + self.rotate.rotate(&self.shape.support(&l))
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct ConvexHull
+///
+/// The convex hull of the points, which need not be on it.
+#[derive(Debug, Clone)]
+pub struct ConvexHull<V>
+where
+    V: Float,
+{
+    /// points
+    points: Vec<Vector3<V>>,
+}
+// ============================================================================
+impl<V> ConvexHull<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    ///
+    /// `Err` if `points` is empty.
+    pub fn new(points: Vec<Vector3<V>>) -> Result<Self> {
+        if points.is_empty() {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_nursery::geometry::ConvexHull::new: empty",
+            )));
+        }
+        Ok(ConvexHull { points })
+    }
+    // ========================================================================
+    /// points
+    pub fn points(&self) -> &[Vector3<V>] {
+        &self.points
+    }
+}
+// ============================================================================
+impl<V> Support<V> for ConvexHull<V>
+where
+    V: Float,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        farthest(&self.points, d)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct MinkowskiSum
+///
+/// e.g. a `Segment` and a `Sphere` make a `Capsule`.
+#[derive(Debug, Clone)]
+pub struct MinkowskiSum<A, B>(pub A, pub B);
+// ============================================================================
+impl<V, A, B> Support<V> for MinkowskiSum<A, B>
+where
+    V: Float,
+    A: Support<V>,
+    B: Support<V>,
+{
+    fn support(&self, d: &Vector3<V>) -> Vector3<V> {
+        self.0.support(d) + self.1.support(d)
+    }
+}
@@ -0,0 +1,53 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! triangle.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Float, Vector3};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Triangle
+#[derive(Debug, Clone)]
+pub struct Triangle<V>
+where
+    V: Float,
+{
+    /// a
+    pub a: Vector3<V>,
+    /// b
+    pub b: Vector3<V>,
+    /// c
+    pub c: Vector3<V>,
+}
+// ============================================================================
+impl<V> Triangle<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    pub fn new(a: Vector3<V>, b: Vector3<V>, c: Vector3<V>) -> Self {
+        Triangle { a, b, c }
+    }
+    // ========================================================================
+    /// normal
+    ///
+    /// Unit, counterclockwise `a`, `b`, `c` face it. Zero if degenerate.
+    /// Divided by its own length, since `normalize` zeroes a short one.
+    pub fn normal(&self) -> Vector3<V> {
+        let n = (self.b - self.a).cross(&(self.c - self.a));
+        let l = n.length();
+        if l == V::zero() {
+            n
+        } else {
+            n / l
+        }
+    }
+}
@@ -128,5 +128,7 @@
 pub mod geometry;
 pub mod physics;
 pub mod sequence;
+#[cfg(test)]
+mod test_util;
 // use  =======================================================================
 pub use sif_error::{Error, Result};
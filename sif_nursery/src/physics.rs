@@ -6,15 +6,21 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/03/21
-//  @date 2018/05/11
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
+use sif_math::{Float, Vector3};
+// ----------------------------------------------------------------------------
+pub use self::body::{Body, Shape};
 pub use self::error::{Error, Result};
-pub use self::rigit::Rigit;
+pub use self::rigit::{Integrator, Rigit};
+pub use self::world::World;
 // mod  =======================================================================
+pub mod body;
 pub mod error;
 pub mod rigit;
+pub mod world;
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// const GRAVITY_F32
@@ -41,3 +47,13 @@ impl Gravity<f64> for f64 {
         GRAVITY_F64
     }
 }
+// ============================================================================
+/// fn gravity
+///
+/// Down the world -Z axis.
+pub fn gravity<V>() -> Vector3<V>
+where
+    V: Float + Gravity<V>,
+{
+    Vector3::new(V::zero(), V::zero(), -V::gravity())
+}
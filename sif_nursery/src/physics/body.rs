@@ -0,0 +1,287 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! body.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_manager::ManagedValue;
+use sif_math::{Float, Quaternion, Vector3};
+use sif_three::{Node, TraRotSca};
+// ----------------------------------------------------------------------------
+use super::{Error, Result, Rigit};
+use crate::geometry::{
+    Capsule, ConvexHull, Cuboid, Obb, Plane, Sphere, Support, Transformed,
+};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// enum Shape
+///
+/// In the body space, around the center of mass.
+#[derive(Debug, Clone)]
+pub enum Shape<V>
+where
+    V: Float,
+{
+    /// Sphere
+    Sphere(Sphere<V>),
+    /// Cuboid
+    Cuboid(Cuboid<V>),
+    /// Capsule
+    Capsule(Capsule<V>),
+    /// Plane
+    ///
+    /// Only for a static body.
+    Plane(Plane<V>),
+    /// Hull
+    Hull(ConvexHull<V>),
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// enum Posed
+///
+/// A `Shape` in the world space.
+#[derive(Debug)]
+pub(crate) enum Posed<'a, V>
+where
+    V: Float,
+{
+    /// Sphere
+    Sphere(Sphere<V>),
+    /// Obb
+    Obb(Obb<V>),
+    /// Capsule
+    Capsule(Capsule<V>),
+    /// Plane
+    Plane(Plane<V>),
+    /// Hull
+    Hull(Transformed<V, &'a ConvexHull<V>>),
+}
+// ============================================================================
+impl<V> Posed<'_, V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// as_support
+    ///
+    /// `None` for a `Plane`.
+    pub(crate) fn as_support(&self) -> Option<&dyn Support<V>> {
+        match *self {
+            Posed::Sphere(ref x) => Some(x),
+            Posed::Obb(ref x) => Some(x),
+            Posed::Capsule(ref x) => Some(x),
+            Posed::Plane(_) => None,
+            Posed::Hull(ref x) => Some(x),
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// bounds
+    ///
+    /// The world axis aligned box, `None` for a `Plane`.
+    pub(crate) fn bounds(&self) -> Option<[Vector3<V>; 2]> {
+        let s = self.as_support()?;
+        let mut ret = [Vector3::default(); 2];
+        for k in 0..3 {
+            let mut d = Vector3::default();
+            d[k] = V::one();
+            ret[1][k] = s.support(&d)[k];
+            d[k] = -V::one();
+            ret[0][k] = s.support(&d)[k];
+        }
+        Some(ret)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Body
+#[derive(Debug, Clone)]
+pub struct Body<V>
+where
+    V: Float,
+{
+    /// rigit
+    ///
+    /// `World::step` integrates it by semi-implicit Euler whatever its
+    /// `integrator`.
+    pub rigit: Rigit<V>,
+    /// shape
+    pub shape: Shape<V>,
+    /// restitution
+    pub restitution: V,
+    /// friction
+    pub friction: V,
+    /// node
+    ///
+    /// Follows the body on `World::write_back`.
+    pub node: Option<ManagedValue<Node<V>>>,
+    /// is_static
+    is_static: bool,
+    /// sleeping
+    sleeping: bool,
+    /// idle
+    ///
+    /// Seconds spent slow enough to sleep.
+    idle: V,
+}
+// ============================================================================
+impl<V> Body<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    ///
+    /// `Err` if `shape` is a `Plane`.
+    pub fn new(rigit: Rigit<V>, shape: Shape<V>) -> Result<Self> {
+        if let Shape::Plane(_) = shape {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_nursery::physics::Body::new: Plane",
+            )));
+        }
+        Ok(Self::new_impl(rigit, shape, false))
+    }
+    // ------------------------------------------------------------------------
+    /// new_static
+    ///
+    /// Never moves.
+    pub fn new_static(
+        shape: Shape<V>,
+        position: Vector3<V>,
+        angle: Quaternion<V>,
+    ) -> Self {
+        let mut rigit = Rigit::default();
+        let _ = rigit.set_position(position).set_angle(angle);
+        Self::new_impl(rigit, shape, true)
+    }
+    // ------------------------------------------------------------------------
+    /// new_impl
+    fn new_impl(rigit: Rigit<V>, shape: Shape<V>, is_static: bool) -> Self {
+        Body {
+            rigit,
+            shape,
+            restitution: V::zero(),
+            friction: V::from(0.5).unwrap(),
+            node: None,
+            is_static,
+            sleeping: false,
+            idle: V::zero(),
+        }
+    }
+    // ========================================================================
+    /// is_static
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+    // ------------------------------------------------------------------------
+    /// is_sleeping
+    pub fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    // ------------------------------------------------------------------------
+    /// is_awake
+    ///
+    /// Dynamic and not sleeping.
+    pub fn is_awake(&self) -> bool {
+        !self.is_static && !self.sleeping
+    }
+    // ------------------------------------------------------------------------
+    /// is_moving
+    ///
+    /// Awake and not slowing down to sleep.
+    pub(crate) fn is_moving(&self) -> bool {
+        self.is_awake() && self.idle == V::zero()
+    }
+    // ------------------------------------------------------------------------
+    /// wake
+    pub fn wake(&mut self) -> &mut Self {
+        self.sleeping = false;
+        self.idle = V::zero();
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// idle
+    ///
+    /// Sleeps after `limit` seconds below the speeds.
+    pub(crate) fn idle(&mut self, dt: V, linear: V, angular: V, limit: V) {
+        let v = *self.rigit.velocity();
+        let w = self.rigit.angular_velocity();
+        if v.dot(&v) > linear * linear || w.dot(&w) > angular * angular {
+            self.idle = V::zero();
+            return;
+        }
+        self.idle += dt;
+        if self.idle >= limit {
+            self.sleeping = true;
+            let _ = self
+                .rigit
+                .set_velocity(Vector3::default())
+                .set_angular_velocity(Vector3::default());
+        }
+    }
+    // ========================================================================
+    /// posed
+    pub(crate) fn posed(&self) -> Posed<'_, V> {
+        let p = *self.rigit.position();
+        let q = *self.rigit.angle();
+        let world = |x: &Vector3<V>| p + q.rotate(x);
+        match self.shape {
+            Shape::Sphere(ref x) => {
+                Posed::Sphere(Sphere::new(world(&x.center), x.radius))
+            }
+            Shape::Cuboid(ref x) => {
+                Posed::Obb(Obb::new(world(&x.center), x.radius, q))
+            }
+            Shape::Capsule(ref x) => Posed::Capsule(Capsule::new(
+                world(&x.start),
+                world(&x.end),
+                x.radius,
+            )),
+            Shape::Plane(ref x) => {
+                let mut n = q.rotate(x.as_normal());
+                let o = world(&(*x.as_normal() * *x.as_distance()));
+                let d = n.dot(&o);
+                Posed::Plane(Plane::new(&mut n, d))
+            }
+            Shape::Hull(ref x) => Posed::Hull(Transformed::new(x, q, p)),
+        }
+    }
+    // ========================================================================
+    /// write_node
+    ///
+    /// Sets the translate and the rotate of `node`, keeping its scale.
+    pub(crate) fn write_node(
+        &self,
+        node: &ManagedValue<Node<V>>,
+    ) -> Result<()> {
+        let one = V::one();
+        let world = TraRotSca::new(
+            *self.rigit.position(),
+            *self.rigit.angle(),
+            Vector3::new(one, one, one),
+        );
+        let mut n = node.as_ref().borrow_mut();
+        let local = match *n.get_parent() {
+            None => world,
+            Some(ref parent) => {
+                let p = parent.upgrade().ok_or_else(|| {
+                    Error::OptNone(String::from(
+                        "::sif_nursery::physics::Body::write_node: \
+                         parent.upgrade",
+                    ))
+                })?;
+                let m = *p.as_ref().borrow_mut().update()?;
+                TraRotSca::from_matrix(&m)?.inverse()?.compose(&world)
+            }
+        };
+        let t: &mut TraRotSca<V> = n.as_mut();
+        t.translate = local.translate;
+        t.rotate = local.rotate;
+        Ok(())
+    }
+}
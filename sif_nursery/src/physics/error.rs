@@ -6,7 +6,7 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/12/10
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
@@ -18,6 +18,8 @@ use std::error::Error as StdError;
 pub enum Error {
     /// OptNone
     OptNone(String),
+    /// InvalidArgument
+    InvalidArgument(String),
     /// NoNode
     NoNode,
     /// InvalidPose
@@ -28,6 +30,8 @@ pub enum Error {
     SifManager(::sif_manager::Error),
     /// SifMath
     SifMath(::sif_math::Error),
+    /// SifThree
+    SifThree(::sif_three::Error),
 }
 // ============================================================================
 impl From<::std::io::Error> for Error {
@@ -47,6 +51,12 @@ impl From<::sif_math::Error> for Error {
         Error::SifMath(e)
     }
 }
+// ----------------------------------------------------------------------------
+impl From<::sif_three::Error> for Error {
+    fn from(e: ::sif_three::Error) -> Self {
+        Error::SifThree(e)
+    }
+}
 // ============================================================================
 impl ::std::fmt::Display for Error {
     // ========================================================================
@@ -60,11 +70,13 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match *self {
             Error::OptNone(_) => None,
+            Error::InvalidArgument(_) => None,
             Error::NoNode => None,
             Error::InvalidPose => None,
             Error::IO(ref e) => Some(e),
             Error::SifManager(ref e) => Some(e),
             Error::SifMath(ref e) => Some(e),
+            Error::SifThree(ref e) => Some(e),
         }
     }
 }
@@ -6,13 +6,24 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/04/09
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
 use sif_math::{Float, Matrix3x3, Quaternion, Vector3};
 // ----------------------------------------------------------------------------
-use super::Result;
+use super::{gravity, Error, Gravity, Result};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// enum Integrator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Integrator {
+    /// SemiImplicitEuler
+    #[default]
+    SemiImplicitEuler,
+    /// RungeKutta4
+    RungeKutta4,
+}
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// struct Rigit
@@ -22,19 +33,41 @@ where
     V: Float,
 {
     /// inertia
+    ///
+    /// In the body space.
     inertia: Matrix3x3<V>,
     /// inertia_inverse
     inertia_inverse: Matrix3x3<V>,
+    /// position
+    ///
+    /// Of the center of mass.
+    position: Vector3<V>,
     /// angle
     angle: Quaternion<V>,
     /// moment
+    ///
+    /// The angular momentum, in the world space.
     moment: Vector3<V>,
     /// velocity
     velocity: Vector3<V>,
     /// force
     pub force: Vector3<V>,
+    /// torque
+    pub torque: Vector3<V>,
     /// mass
     mass: V,
+    /// linear_damping
+    ///
+    /// The rate per second that `velocity` loses.
+    pub linear_damping: V,
+    /// angular_damping
+    pub angular_damping: V,
+    /// gravity_scale
+    pub gravity_scale: V,
+    /// integrator
+    ///
+    /// Of `Rigit::step`, not of `World::step`.
+    pub integrator: Integrator,
 }
 // ============================================================================
 impl<V> Default for Rigit<V>
@@ -46,11 +79,17 @@ where
         Rigit {
             inertia: Matrix3x3::default(),
             inertia_inverse: Matrix3x3::default(),
+            position: Vector3::default(),
             angle: Quaternion::default(),
             moment: Vector3::default(),
             velocity: Vector3::default(),
             force: Vector3::default(),
+            torque: Vector3::default(),
             mass: V::one(),
+            linear_damping: V::zero(),
+            angular_damping: V::zero(),
+            gravity_scale: V::one(),
+            integrator: Integrator::default(),
         }
     }
 }
@@ -61,15 +100,430 @@ where
 {
     // ========================================================================
     /// new
+    ///
+    /// `Err` if `mass` is not positive or `inertia` is singular.
     pub fn new(mass: V, inertia: Matrix3x3<V>) -> Result<Self> {
+        if mass.is_nan() || mass <= V::zero() {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_nursery::physics::Rigit::new: mass",
+            )));
+        }
         Ok(Rigit {
             inertia,
             inertia_inverse: inertia.new_inverse()?,
-            angle: Quaternion::default(),
-            moment: Vector3::default(),
-            velocity: Vector3::default(),
-            force: Vector3::default(),
             mass,
+            ..Default::default()
         })
     }
+    // ========================================================================
+    /// mass
+    pub fn mass(&self) -> V {
+        self.mass
+    }
+    // ------------------------------------------------------------------------
+    /// inertia
+    pub fn inertia(&self) -> &Matrix3x3<V> {
+        &self.inertia
+    }
+    // ------------------------------------------------------------------------
+    /// inertia_inverse
+    pub fn inertia_inverse(&self) -> &Matrix3x3<V> {
+        &self.inertia_inverse
+    }
+    // ------------------------------------------------------------------------
+    /// world
+    ///
+    /// `m` turned into the world space by `angle`.
+    fn world(angle: &Quaternion<V>, m: &Matrix3x3<V>) -> Matrix3x3<V> {
+        let r = Matrix3x3::from(*angle);
+        let mut t = r;
+        let _ = t.transpose();
+        r * *m * t
+    }
+    // ------------------------------------------------------------------------
+    /// world_inertia
+    pub fn world_inertia(&self) -> Matrix3x3<V> {
+        Self::world(&self.angle, &self.inertia)
+    }
+    // ------------------------------------------------------------------------
+    /// world_inertia_inverse
+    pub fn world_inertia_inverse(&self) -> Matrix3x3<V> {
+        Self::world(&self.angle, &self.inertia_inverse)
+    }
+    // ========================================================================
+    /// position
+    pub fn position(&self) -> &Vector3<V> {
+        &self.position
+    }
+    // ------------------------------------------------------------------------
+    /// set_position
+    pub fn set_position(&mut self, position: Vector3<V>) -> &mut Self {
+        self.position = position;
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// angle
+    pub fn angle(&self) -> &Quaternion<V> {
+        &self.angle
+    }
+    // ------------------------------------------------------------------------
+    /// set_angle
+    ///
+    /// Keeps the angular velocity.
+    pub fn set_angle(&mut self, angle: Quaternion<V>) -> &mut Self {
+        let omega = self.angular_velocity();
+        self.angle = angle;
+        let _ = self.angle.normalize();
+        self.set_angular_velocity(omega)
+    }
+    // ------------------------------------------------------------------------
+    /// velocity
+    pub fn velocity(&self) -> &Vector3<V> {
+        &self.velocity
+    }
+    // ------------------------------------------------------------------------
+    /// set_velocity
+    pub fn set_velocity(&mut self, velocity: Vector3<V>) -> &mut Self {
+        self.velocity = velocity;
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// moment
+    pub fn moment(&self) -> &Vector3<V> {
+        &self.moment
+    }
+    // ------------------------------------------------------------------------
+    /// angular_velocity
+    pub fn angular_velocity(&self) -> Vector3<V> {
+        self.world_inertia_inverse() * self.moment
+    }
+    // ------------------------------------------------------------------------
+    /// set_angular_velocity
+    pub fn set_angular_velocity(&mut self, omega: Vector3<V>) -> &mut Self {
+        self.moment = self.world_inertia() * omega;
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// velocity_at
+    ///
+    /// Of the world `point` moving with the body.
+    pub fn velocity_at(&self, point: &Vector3<V>) -> Vector3<V> {
+        self.velocity
+            + self.angular_velocity().cross(&(*point - self.position))
+    }
+    // ========================================================================
+    /// momentum
+    pub fn momentum(&self) -> Vector3<V> {
+        self.velocity * self.mass
+    }
+    // ------------------------------------------------------------------------
+    /// angular_momentum
+    ///
+    /// About the world origin.
+    pub fn angular_momentum(&self) -> Vector3<V> {
+        self.moment + self.position.cross(&self.momentum())
+    }
+    // ------------------------------------------------------------------------
+    /// kinetic_energy
+    pub fn kinetic_energy(&self) -> V {
+        let two = V::one() + V::one();
+        (self.mass * self.velocity.dot(&self.velocity)
+            + self.angular_velocity().dot(&self.moment))
+            / two
+    }
+    // ========================================================================
+    /// apply_force
+    ///
+    /// Through the center of mass, until the next `step`.
+    pub fn apply_force(&mut self, force: &Vector3<V>) -> &mut Self {
+        self.force += *force;
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// apply_force_at
+    ///
+    /// At the world `point`, until the next `step`.
+    pub fn apply_force_at(
+        &mut self,
+        force: &Vector3<V>,
+        point: &Vector3<V>,
+    ) -> &mut Self {
+        self.torque += (*point - self.position).cross(force);
+        self.apply_force(force)
+    }
+    // ------------------------------------------------------------------------
+    /// apply_torque
+    pub fn apply_torque(&mut self, torque: &Vector3<V>) -> &mut Self {
+        self.torque += *torque;
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// apply_impulse
+    pub fn apply_impulse(&mut self, impulse: &Vector3<V>) -> &mut Self {
+        self.velocity += *impulse / self.mass;
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// apply_impulse_at
+    ///
+    /// At the world `point`.
+    pub fn apply_impulse_at(
+        &mut self,
+        impulse: &Vector3<V>,
+        point: &Vector3<V>,
+    ) -> &mut Self {
+        self.moment += (*point - self.position).cross(impulse);
+        self.apply_impulse(impulse)
+    }
+    // ------------------------------------------------------------------------
+    /// apply_angular_impulse
+    pub fn apply_angular_impulse(
+        &mut self,
+        impulse: &Vector3<V>,
+    ) -> &mut Self {
+        self.moment += *impulse;
+        self
+    }
+    // ========================================================================
+    /// spin
+    ///
+    /// The derivative of `angle`.
+    fn spin(
+        &self,
+        angle: &Quaternion<V>,
+        moment: &Vector3<V>,
+    ) -> Quaternion<V> {
+        let omega = Self::world(angle, &self.inertia_inverse) * *moment;
+        Quaternion::from_vector3(&omega, V::zero()) * *angle * Self::half()
+    }
+    // ------------------------------------------------------------------------
+    /// half
+    fn half() -> V {
+        V::one() / (V::one() + V::one())
+    }
+    // ------------------------------------------------------------------------
+    /// damp
+    fn damp(rate: V, dt: V) -> V {
+        V::one() / (V::one() + rate * dt)
+    }
+    // ========================================================================
+    /// step
+    ///
+    /// Advances `dt` seconds by `integrator`, then clears `force` and
+    /// `torque`.
+    pub fn step(&mut self, dt: V) -> &mut Self
+    where
+        V: Gravity<V>,
+    {
+        match self.integrator {
+            Integrator::SemiImplicitEuler => {
+                self.integrate_velocity(dt).integrate_position(dt)
+            }
+            Integrator::RungeKutta4 => {
+                let acceleration = self.acceleration();
+                self.runge_kutta4(&acceleration, dt);
+                self.damping(dt);
+                let _ = self.angle.normalize();
+                self.clear()
+            }
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// integrate_velocity
+    ///
+    /// The first half of a semi-implicit Euler `step`, which a contact
+    /// solver may run between.
+    pub fn integrate_velocity(&mut self, dt: V) -> &mut Self
+    where
+        V: Gravity<V>,
+    {
+        self.velocity += self.acceleration() * dt;
+        self.moment += self.torque * dt;
+        self.damping(dt);
+        self.clear()
+    }
+    // ------------------------------------------------------------------------
+    /// integrate_position
+    ///
+    /// The second half of a semi-implicit Euler `step`.
+    pub fn integrate_position(&mut self, dt: V) -> &mut Self {
+        self.position += self.velocity * dt;
+        let omega = self.angular_velocity() * (dt * Self::half());
+        self.angle =
+            Quaternion::from_vector3(&omega, V::zero()).exp() * self.angle;
+        let _ = self.angle.normalize();
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// acceleration
+    fn acceleration(&self) -> Vector3<V>
+    where
+        V: Gravity<V>,
+    {
+        self.force / self.mass + gravity::<V>() * self.gravity_scale
+    }
+    // ------------------------------------------------------------------------
+    /// clear
+    fn clear(&mut self) -> &mut Self {
+        self.force = Vector3::default();
+        self.torque = Vector3::default();
+        self
+    }
+    // ------------------------------------------------------------------------
+    /// damping
+    fn damping(&mut self, dt: V) {
+        self.velocity *= Self::damp(self.linear_damping, dt);
+        self.moment *= Self::damp(self.angular_damping, dt);
+    }
+    // ------------------------------------------------------------------------
+    /// runge_kutta4
+    ///
+    /// `acceleration` and `torque` stay constant over `dt`.
+    fn runge_kutta4(&mut self, acceleration: &Vector3<V>, dt: V) {
+        let h = dt * Self::half();
+        let six = V::from(6).unwrap();
+        let two = V::one() + V::one();
+        // position and velocity are exact for a constant acceleration
+        self.position += (self.velocity + *acceleration * h) * dt;
+        self.velocity += *acceleration * dt;
+        let (q, l, t) = (self.angle, self.moment, self.torque);
+        let k1 = self.spin(&q, &l);
+        let k2 = self.spin(&(q + k1 * h), &(l + t * h));
+        let k3 = self.spin(&(q + k2 * h), &(l + t * h));
+        let k4 = self.spin(&(q + k3 * dt), &(l + t * dt));
+        self.angle = q + (k1 + k2 * two + k3 * two + k4) * (dt / six);
+        self.moment = l + t * dt;
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::test_util::v;
+    // ========================================================================
+    fn body(integrator: Integrator) -> Rigit<f64> {
+        let inertia = Matrix3x3::from([
+            v(1.0, 0.0, 0.0),
+            v(0.0, 2.0, 0.0),
+            v(0.0, 0.0, 3.0),
+        ]);
+        let mut r = Rigit::new(2.0, inertia).unwrap();
+        r.gravity_scale = 0.0;
+        r.integrator = integrator;
+        r
+    }
+    // ========================================================================
+    #[test]
+    fn spin() {
+        for &i in &[Integrator::SemiImplicitEuler, Integrator::RungeKutta4] {
+            let mut r = body(i);
+            let _ = r.set_angular_velocity(v(0.0, 0.0, 1.5));
+            for _ in 0..100 {
+                let _ = r.step(0.01);
+            }
+            let q =
+                Quaternion::from_axis_angle(&v(0.0, 0.0, 1.0), 1.5).unwrap();
+            assert!((*r.angle() - q).length() < 1.0e-9, "{:?}", r.angle());
+            assert!((r.angle().length() - 1.0).abs() < 1.0e-12);
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn free_body() {
+        // tumbling about all three axes, close to the unstable middle one
+        for &(i, tolerance) in &[
+            (Integrator::SemiImplicitEuler, 5.0e-2),
+            (Integrator::RungeKutta4, 1.0e-7),
+        ] {
+            let mut r = body(i);
+            let _ = r.set_angular_velocity(v(0.3, 2.0, 0.5));
+            let _ = r.set_velocity(v(1.0, -2.0, 0.5));
+            let energy = r.kinetic_energy();
+            let moment = r.angular_momentum();
+            for _ in 0..1000 {
+                let _ = r.step(0.01);
+                let e = r.kinetic_energy();
+                assert!((e - energy).abs() < tolerance * energy, "{:?}", i);
+                let l = r.angular_momentum();
+                assert!((l - moment).length() < 1.0e-9, "{:?}", l);
+            }
+            assert!((*r.position() - v(10.0, -20.0, 5.0)).length() < 1.0e-9);
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn new() {
+        let inertia = Matrix3x3::new_identity();
+        assert!(Rigit::new(0.0, inertia).is_err());
+        assert!(Rigit::new(-1.0, inertia).is_err());
+        assert!(Rigit::new(f64::NAN, inertia).is_err());
+        assert!(Rigit::new(1.0, inertia * 0.0).is_err());
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn apply() {
+        let mut r = body(Integrator::SemiImplicitEuler);
+        let _ = r.set_position(v(1.0, 0.0, 0.0));
+        // off center, about the world origin nothing changes
+        let _ = r.apply_impulse_at(&v(0.0, 4.0, 0.0), &v(1.0, 0.0, 0.5));
+        assert!((*r.velocity() - v(0.0, 2.0, 0.0)).length() < 1.0e-12);
+        assert!((*r.moment() - v(-2.0, 0.0, 0.0)).length() < 1.0e-12);
+        assert!((r.angular_velocity() - v(-2.0, 0.0, 0.0)).length() < 1.0e-12);
+        assert!(
+            (r.velocity_at(&v(1.0, 0.0, 1.0)) - v(0.0, 4.0, 0.0)).length()
+                < 1.0e-12
+        );
+        let _ = r
+            .apply_force_at(&v(0.0, 0.0, 2.0), &v(1.0, 1.0, 0.0))
+            .step(0.5);
+        assert!((*r.velocity() - v(0.0, 2.0, 0.5)).length() < 1.0e-12);
+        assert!((*r.moment() - v(-1.0, 0.0, 0.0)).length() < 1.0e-12);
+        assert_eq!(r.force, Vector3::default());
+        assert_eq!(r.torque, Vector3::default());
+
+        // world inertia follows the angle
+        let q = Quaternion::from_axis_angle(&v(0.0, 0.0, 1.0), 1.0).unwrap();
+        let _ = r.set_angle(q);
+        let i =
+            r.world_inertia() * (r.world_inertia_inverse() * v(1.0, 2.0, 3.0));
+        assert!((i - v(1.0, 2.0, 3.0)).length() < 1.0e-12);
+        let axis = q.rotate(&v(1.0, 0.0, 0.0));
+        assert!((r.world_inertia() * axis - axis).length() < 1.0e-12);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn fall() {
+        for &i in &[Integrator::SemiImplicitEuler, Integrator::RungeKutta4] {
+            let mut r = body(i);
+            r.gravity_scale = 1.0;
+            let t = 100;
+            for _ in 0..t {
+                let _ = r.step(0.01);
+            }
+            let g = gravity::<f64>();
+            assert!((r.momentum() - g * 2.0).length() < 1.0e-9);
+            let z = r.position()[2];
+            match i {
+                // exact
+                Integrator::RungeKutta4 => {
+                    assert!((z - g[2] / 2.0).abs() < 1.0e-9)
+                }
+                // half a step ahead
+                Integrator::SemiImplicitEuler => {
+                    assert!((z - g[2] * 1.01 / 2.0).abs() < 1.0e-9, "{}", z)
+                }
+            }
+        }
+
+        let mut r = body(Integrator::SemiImplicitEuler);
+        r.linear_damping = 1.0;
+        r.angular_damping = 1.0;
+        let _ = r.set_velocity(v(1.0, 0.0, 0.0));
+        let _ = r.set_angular_velocity(v(1.0, 0.0, 0.0));
+        let _ = r.step(1.0);
+        assert!((*r.velocity() - v(0.5, 0.0, 0.0)).length() < 1.0e-12);
+        assert!((r.angular_velocity() - v(0.5, 0.0, 0.0)).length() < 1.0e-12);
+    }
 }
@@ -0,0 +1,821 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! world.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use std::collections::BTreeMap;
+// ----------------------------------------------------------------------------
+use sif_math::{Float, Matrix3x3, Vector3};
+// ----------------------------------------------------------------------------
+use super::{body::Posed, Body, Gravity, Result};
+use crate::geometry::{
+    epa, Capsule, Collide, Contact, ContactPoint, ConvexHull, Obb, Plane,
+    Sphere, Transformed,
+};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// analytic
+fn analytic<V, A>(a: &A, b: &Posed<'_, V>) -> Option<Contact<V>>
+where
+    V: Float,
+    A: Collide<V, Sphere<V>>
+        + Collide<V, Obb<V>>
+        + Collide<V, Capsule<V>>
+        + Collide<V, Plane<V>>,
+{
+    match *b {
+        Posed::Sphere(ref x) => a.contact(x),
+        Posed::Obb(ref x) => a.contact(x),
+        Posed::Capsule(ref x) => a.contact(x),
+        Posed::Plane(ref x) => a.contact(x),
+        Posed::Hull(_) => None,
+    }
+}
+// ----------------------------------------------------------------------------
+/// hull_plane
+///
+/// Every point of the hull behind the plane.
+fn hull_plane<V: Float>(
+    hull: &Transformed<V, &ConvexHull<V>>,
+    plane: &Plane<V>,
+) -> Option<Contact<V>> {
+    let n = *plane.as_normal();
+    let half = V::from(0.5).unwrap();
+    let points: Vec<ContactPoint<V>> = hull
+        .shape
+        .points()
+        .iter()
+        .filter_map(|p| {
+            let w = hull.translate + hull.rotate.rotate(p);
+            let d = plane.signed_distance(&w);
+            if d < V::zero() {
+                Some(ContactPoint {
+                    point: w - n * (d * half),
+                    depth: -d,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    if points.is_empty() {
+        return None;
+    }
+    Some(Contact {
+        normal: n * -V::one(),
+        depth: points.iter().fold(V::zero(), |m, x| m.max(x.depth)),
+        points,
+    })
+}
+// ----------------------------------------------------------------------------
+/// collide
+///
+/// The narrowphase.
+fn collide<V: Float>(
+    a: &Posed<'_, V>,
+    b: &Posed<'_, V>,
+) -> Option<Contact<V>> {
+    match (a, b) {
+        (Posed::Hull(x), Posed::Plane(p)) => hull_plane(x, p),
+        (Posed::Plane(p), Posed::Hull(x)) => {
+            hull_plane(x, p).map(Contact::flip)
+        }
+        (Posed::Hull(_), _) | (_, Posed::Hull(_)) => {
+            epa(a.as_support()?, b.as_support()?)
+        }
+        (Posed::Sphere(x), _) => analytic(x, b),
+        (Posed::Obb(x), _) => analytic(x, b),
+        (Posed::Capsule(x), _) => analytic(x, b),
+        (Posed::Plane(x), _) => analytic(x, b),
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Motion
+///
+/// The velocities the solver works on.
+#[derive(Debug, Clone, Copy)]
+struct Motion<V>
+where
+    V: Float,
+{
+    /// velocity
+    velocity: Vector3<V>,
+    /// omega
+    omega: Vector3<V>,
+    /// mass_inverse
+    mass_inverse: V,
+    /// inertia_inverse
+    inertia_inverse: Matrix3x3<V>,
+}
+// ============================================================================
+impl<V> Motion<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    ///
+    /// A body which is not awake does not move.
+    fn new(body: &Body<V>) -> Self {
+        if body.is_awake() {
+            Motion {
+                velocity: *body.rigit.velocity(),
+                omega: body.rigit.angular_velocity(),
+                mass_inverse: V::one() / body.rigit.mass(),
+                inertia_inverse: body.rigit.world_inertia_inverse(),
+            }
+        } else {
+            Motion {
+                velocity: Vector3::default(),
+                omega: Vector3::default(),
+                mass_inverse: V::zero(),
+                inertia_inverse: Matrix3x3::from_no_clean(Default::default()),
+            }
+        }
+    }
+    // ========================================================================
+    /// at
+    fn at(&self, r: &Vector3<V>) -> Vector3<V> {
+        self.velocity + self.omega.cross(r)
+    }
+    // ------------------------------------------------------------------------
+    /// apply
+    fn apply(&mut self, impulse: &Vector3<V>, r: &Vector3<V>) {
+        self.velocity += *impulse * self.mass_inverse;
+        self.omega += self.inertia_inverse * r.cross(impulse);
+    }
+    // ------------------------------------------------------------------------
+    /// mass
+    ///
+    /// The inverse of the effective mass along `n` at `r`.
+    fn mass(&self, n: &Vector3<V>, r: &Vector3<V>) -> V {
+        let rn = r.cross(n);
+        self.mass_inverse + (self.inertia_inverse * rn).cross(r).dot(n)
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Cached
+///
+/// The impulses of a contact point, for warm starting.
+#[derive(Debug, Clone, Copy)]
+struct Cached<V>
+where
+    V: Float,
+{
+    /// point
+    point: Vector3<V>,
+    /// normal
+    normal: V,
+    /// friction
+    friction: Vector3<V>,
+}
+// ============================================================================
+/// struct Point
+#[derive(Debug, Clone, Copy)]
+struct Point<V>
+where
+    V: Float,
+{
+    /// point
+    point: Vector3<V>,
+    /// ra
+    ra: Vector3<V>,
+    /// rb
+    rb: Vector3<V>,
+    /// bias
+    ///
+    /// The separating speed to reach.
+    bias: V,
+    /// normal_mass
+    normal_mass: V,
+    /// tangent_mass
+    tangent_mass: [V; 2],
+    /// normal
+    normal: V,
+    /// tangent
+    tangent: [V; 2],
+}
+// ============================================================================
+/// struct Manifold
+#[derive(Debug, Clone)]
+struct Manifold<V>
+where
+    V: Float,
+{
+    /// a
+    a: usize,
+    /// b
+    b: usize,
+    /// normal
+    normal: Vector3<V>,
+    /// tangent
+    tangent: [Vector3<V>; 2],
+    /// friction
+    friction: V,
+    /// points
+    points: Vec<Point<V>>,
+}
+// ============================================================================
+impl<V> Manifold<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// impulse
+    fn impulse(&self, p: &Point<V>) -> Vector3<V> {
+        self.normal * p.normal
+            + self.tangent[0] * p.tangent[0]
+            + self.tangent[1] * p.tangent[1]
+    }
+    // ------------------------------------------------------------------------
+    /// cache
+    fn cache(&self) -> Vec<Cached<V>> {
+        self.points
+            .iter()
+            .map(|p| Cached {
+                point: p.point,
+                normal: p.normal,
+                friction: self.impulse(p) - self.normal * p.normal,
+            })
+            .collect()
+    }
+    // ------------------------------------------------------------------------
+    /// solve
+    ///
+    /// One pass of sequential impulses, friction first.
+    fn solve(&mut self, motions: &mut [Motion<V>]) {
+        let (mut ma, mut mb) = (motions[self.a], motions[self.b]);
+        for p in &mut self.points {
+            for k in 0..2 {
+                let t = self.tangent[k];
+                let vt = (mb.at(&p.rb) - ma.at(&p.ra)).dot(&t);
+                let limit = self.friction * p.normal;
+                let old = p.tangent[k];
+                p.tangent[k] =
+                    (old - vt * p.tangent_mass[k]).max(-limit).min(limit);
+                let j = t * (p.tangent[k] - old);
+                ma.apply(&(j * -V::one()), &p.ra);
+                mb.apply(&j, &p.rb);
+            }
+            let vn = (mb.at(&p.rb) - ma.at(&p.ra)).dot(&self.normal);
+            let old = p.normal;
+            p.normal = (old + (p.bias - vn) * p.normal_mass).max(V::zero());
+            let j = self.normal * (p.normal - old);
+            ma.apply(&(j * -V::one()), &p.ra);
+            mb.apply(&j, &p.rb);
+        }
+        motions[self.a] = ma;
+        motions[self.b] = mb;
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// tangents
+fn tangents<V: Float>(n: &Vector3<V>) -> [Vector3<V>; 2] {
+    let axis = if n[0].abs() < V::from(0.57).unwrap() {
+        Vector3::new(V::one(), V::zero(), V::zero())
+    } else {
+        Vector3::new(V::zero(), V::one(), V::zero())
+    };
+    let t = *n.cross(&axis).normalize();
+    [t, n.cross(&t)]
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct World
+#[derive(Debug, Clone)]
+pub struct World<V>
+where
+    V: Float,
+{
+    /// bodies
+    bodies: Vec<Body<V>>,
+    /// cache
+    cache: BTreeMap<(usize, usize), Vec<Cached<V>>>,
+    /// iterations
+    ///
+    /// Of the solver in a step.
+    pub iterations: usize,
+    /// baumgarte
+    ///
+    /// The part of the penetration to resolve in a step.
+    pub baumgarte: V,
+    /// slop
+    ///
+    /// The penetration to allow.
+    pub slop: V,
+    /// restitution_threshold
+    ///
+    /// The slowest approach to bounce.
+    pub restitution_threshold: V,
+    /// contact_tolerance
+    ///
+    /// How far a contact point may move and still be warm started.
+    pub contact_tolerance: V,
+    /// sleep_linear
+    pub sleep_linear: V,
+    /// sleep_angular
+    pub sleep_angular: V,
+    /// sleep_time
+    ///
+    /// Seconds below `sleep_linear` and `sleep_angular` to fall asleep.
+    pub sleep_time: V,
+}
+// ============================================================================
+impl<V> Default for World<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    fn default() -> Self {
+        let f = |x: f64| V::from(x).unwrap();
+        World {
+            bodies: Vec::new(),
+            cache: BTreeMap::new(),
+            iterations: 10,
+            baumgarte: f(0.2),
+            slop: f(0.005),
+            restitution_threshold: f(0.5),
+            contact_tolerance: f(0.05),
+            sleep_linear: f(0.05),
+            sleep_angular: f(0.05),
+            sleep_time: f(0.5),
+        }
+    }
+}
+// ============================================================================
+impl<V> World<V>
+where
+    V: Float,
+{
+    // ========================================================================
+    /// new
+    pub fn new() -> Self {
+        Self::default()
+    }
+    // ========================================================================
+    /// insert
+    ///
+    /// Returns the index of `body`.
+    pub fn insert(&mut self, body: Body<V>) -> usize {
+        self.bodies.push(body);
+        self.bodies.len() - 1
+    }
+    // ------------------------------------------------------------------------
+    /// bodies
+    pub fn bodies(&self) -> &[Body<V>] {
+        &self.bodies
+    }
+    // ------------------------------------------------------------------------
+    /// body
+    pub fn body(&self, index: usize) -> Option<&Body<V>> {
+        self.bodies.get(index)
+    }
+    // ------------------------------------------------------------------------
+    /// body_mut
+    ///
+    /// Wakes the body.
+    pub fn body_mut(&mut self, index: usize) -> Option<&mut Body<V>> {
+        self.bodies.get_mut(index).map(|b| b.wake())
+    }
+    // ========================================================================
+    /// broadphase
+    ///
+    /// Sweep and prune along X. Pairs at least one awake body.
+    fn broadphase(&self, posed: &[Posed<'_, V>]) -> Vec<(usize, usize)> {
+        let mut bounded = Vec::new();
+        let mut planes = Vec::new();
+        for (i, p) in posed.iter().enumerate() {
+            match p.bounds() {
+                Some(b) => bounded.push((i, b)),
+                None => planes.push(i),
+            }
+        }
+        bounded.sort_by(|x, y| x.1[0][0].partial_cmp(&y.1[0][0]).unwrap());
+        let mut ret = Vec::new();
+        for (n, &(i, ref bi)) in bounded.iter().enumerate() {
+            for &(j, ref bj) in &bounded[n + 1..] {
+                if bi[1][0] < bj[0][0] {
+                    break;
+                }
+                if (1..3).all(|k| bj[0][k] <= bi[1][k] && bi[0][k] <= bj[1][k])
+                {
+                    ret.push((i.min(j), i.max(j)));
+                }
+            }
+            for &j in &planes {
+                ret.push((i.min(j), i.max(j)));
+            }
+        }
+        ret.retain(|&(i, j)| {
+            self.bodies[i].is_awake() || self.bodies[j].is_awake()
+        });
+        ret
+    }
+    // ------------------------------------------------------------------------
+    /// manifold
+    fn manifold(
+        &self,
+        a: usize,
+        b: usize,
+        contact: &Contact<V>,
+        motions: &[Motion<V>],
+        dt: V,
+    ) -> Manifold<V> {
+        let (ba, bb) = (&self.bodies[a], &self.bodies[b]);
+        let (ma, mb) = (&motions[a], &motions[b]);
+        let n = contact.normal;
+        let tangent = tangents(&n);
+        let restitution = ba.restitution.max(bb.restitution);
+        let cached = self.cache.get(&(a, b));
+        let points = contact
+            .points
+            .iter()
+            .map(|c| {
+                let ra = c.point - *ba.rigit.position();
+                let rb = c.point - *bb.rigit.position();
+                let mass = |d: &Vector3<V>| {
+                    let k = ma.mass(d, &ra) + mb.mass(d, &rb);
+                    if k > V::zero() {
+                        V::one() / k
+                    } else {
+                        V::zero()
+                    }
+                };
+                let vn = (mb.at(&rb) - ma.at(&ra)).dot(&n);
+                let bounce = if vn < -self.restitution_threshold {
+                    -restitution * vn
+                } else {
+                    V::zero()
+                };
+                let push =
+                    (c.depth - self.slop).max(V::zero()) * self.baumgarte / dt;
+                let mut p = Point {
+                    point: c.point,
+                    ra,
+                    rb,
+                    bias: bounce.max(push),
+                    normal_mass: mass(&n),
+                    tangent_mass: [mass(&tangent[0]), mass(&tangent[1])],
+                    normal: V::zero(),
+                    tangent: [V::zero(); 2],
+                };
+                let old = cached.and_then(|v| {
+                    v.iter().find(|x| {
+                        (x.point - c.point).length() < self.contact_tolerance
+                    })
+                });
+                if let Some(x) = old {
+                    p.normal = x.normal;
+                    p.tangent = [
+                        x.friction.dot(&tangent[0]),
+                        x.friction.dot(&tangent[1]),
+                    ];
+                }
+                p
+            })
+            .collect();
+        Manifold {
+            a,
+            b,
+            normal: n,
+            tangent,
+            friction: (ba.friction * bb.friction).sqrt(),
+            points,
+        }
+    }
+    // ========================================================================
+    /// step
+    ///
+    /// Advances `dt` seconds by semi-implicit Euler, resolving contacts
+    /// between the velocity and the position updates. The `integrator` of
+    /// each `Rigit` is ignored, the solver needs the velocities in between.
+    pub fn step(&mut self, dt: V) -> &mut Self
+    where
+        V: Gravity<V>,
+    {
+        for b in self.bodies.iter_mut().filter(|b| b.is_awake()) {
+            let _ = b.rigit.integrate_velocity(dt);
+        }
+        // collision
+        let contacts: Vec<(usize, usize, Contact<V>)> = {
+            let posed: Vec<Posed<'_, V>> =
+                self.bodies.iter().map(Body::posed).collect();
+            self.broadphase(&posed)
+                .into_iter()
+                .filter_map(|(a, b)| {
+                    collide(&posed[a], &posed[b]).map(|c| (a, b, c))
+                })
+                .collect()
+        };
+        // a sleeping body wakes when hit by a moving one
+        for &(a, b, _) in &contacts {
+            for &(x, y) in &[(a, b), (b, a)] {
+                if self.bodies[x].is_sleeping() && self.bodies[y].is_moving() {
+                    let _ = self.bodies[x].wake();
+                }
+            }
+        }
+        // solve
+        let mut motions: Vec<Motion<V>> =
+            self.bodies.iter().map(Motion::new).collect();
+        let mut manifolds: Vec<Manifold<V>> = contacts
+            .iter()
+            .map(|&(a, b, ref c)| self.manifold(a, b, c, &motions, dt))
+            .collect();
+        for m in &manifolds {
+            for p in &m.points {
+                let j = m.impulse(p);
+                motions[m.a].apply(&(j * -V::one()), &p.ra);
+                motions[m.b].apply(&j, &p.rb);
+            }
+        }
+        for _ in 0..self.iterations {
+            for m in &mut manifolds {
+                m.solve(&mut motions);
+            }
+        }
+        self.cache =
+            manifolds.iter().map(|m| ((m.a, m.b), m.cache())).collect();
+        // integrate
+        let (linear, angular, time) =
+            (self.sleep_linear, self.sleep_angular, self.sleep_time);
+        for (b, m) in self.bodies.iter_mut().zip(&motions) {
+            if !b.is_awake() {
+                continue;
+            }
+            let _ = b
+                .rigit
+                .set_velocity(m.velocity)
+                .set_angular_velocity(m.omega)
+                .integrate_position(dt);
+            b.idle(dt, linear, angular, time);
+        }
+        self
+    }
+    // ========================================================================
+    /// write_back
+    ///
+    /// Moves the node of every body to it.
+    pub fn write_back(&self) -> Result<()> {
+        for b in &self.bodies {
+            if let Some(ref node) = b.node {
+                b.write_node(node)?;
+            }
+        }
+        Ok(())
+    }
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use crate::geometry::Cuboid;
+    use crate::physics::{gravity, Rigit, Shape};
+    use crate::test_util::v;
+    use sif_manager::ManagedValue;
+    use sif_math::{Quaternion, Vector4};
+    use sif_three::Node;
+    use uuid::Uuid;
+    // ========================================================================
+    fn ground(world: &mut World<f64>) -> usize {
+        let plane = Plane::new(&mut v(0.0, 0.0, 1.0), 0.0);
+        world.insert(Body::new_static(
+            Shape::Plane(plane),
+            Vector3::default(),
+            Quaternion::default(),
+        ))
+    }
+    // ------------------------------------------------------------------------
+    fn sphere(world: &mut World<f64>, p: Vector3<f64>, r: f64) -> usize {
+        let i = 0.4 * r * r;
+        let mut rigit = Rigit::new(1.0, diagonal(i, i, i)).unwrap();
+        let _ = rigit.set_position(p);
+        let shape = Shape::Sphere(Sphere::new(Vector3::default(), r));
+        world.insert(Body::new(rigit, shape).unwrap())
+    }
+    // ------------------------------------------------------------------------
+    fn cuboid(
+        world: &mut World<f64>,
+        p: Vector3<f64>,
+        r: Vector3<f64>,
+    ) -> usize {
+        let m = 1.0;
+        let (x, y, z) = (r[0] * r[0], r[1] * r[1], r[2] * r[2]);
+        let mut rigit = Rigit::new(
+            m,
+            diagonal((y + z) / 3.0, (x + z) / 3.0, (x + y) / 3.0),
+        )
+        .unwrap();
+        let _ = rigit.set_position(p);
+        let shape = Shape::Cuboid(Cuboid::new(Vector3::default(), r));
+        world.insert(Body::new(rigit, shape).unwrap())
+    }
+    // ------------------------------------------------------------------------
+    fn diagonal(x: f64, y: f64, z: f64) -> Matrix3x3<f64> {
+        Matrix3x3::from([v(x, 0.0, 0.0), v(0.0, y, 0.0), v(0.0, 0.0, z)])
+    }
+    // ------------------------------------------------------------------------
+    fn run(world: &mut World<f64>, seconds: f64) {
+        for _ in 0..(seconds * 60.0) as usize {
+            let _ = world.step(1.0 / 60.0);
+        }
+    }
+    // ========================================================================
+    #[test]
+    fn rest() {
+        let mut w = World::new();
+        let _ = ground(&mut w);
+        let s = sphere(&mut w, v(0.0, 0.0, 2.0), 0.5);
+        run(&mut w, 1.0);
+        let b = w.body(s).unwrap();
+        assert!((b.rigit.position()[2] - 0.5).abs() < 0.01, "{:?}", b);
+        run(&mut w, 2.0);
+        let b = w.body(s).unwrap();
+        assert!(b.is_sleeping(), "{:?}", b);
+        assert!((b.rigit.position()[2] - 0.5).abs() < 0.01, "{:?}", b);
+        assert!(
+            Body::new(Rigit::default(), w.body(0).unwrap().shape.clone())
+                .is_err()
+        );
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn stack() {
+        let mut w = World::new();
+        let _ = ground(&mut w);
+        let boxes: Vec<usize> = (0..4)
+            .map(|i| {
+                let p = v(0.01 * i as f64, 0.0, 0.5 + 1.05 * i as f64);
+                cuboid(&mut w, p, v(0.5, 0.5, 0.5))
+            })
+            .collect();
+        run(&mut w, 4.0);
+        for (i, &b) in boxes.iter().enumerate() {
+            let b = w.body(b).unwrap();
+            let p = b.rigit.position();
+            assert!((p[2] - (0.5 + i as f64)).abs() < 0.03, "{} {:?}", i, p);
+            assert!((p[0] - 0.01 * i as f64).abs() < 0.03, "{} {:?}", i, p);
+            assert!(b.is_sleeping(), "{} {:?}", i, b);
+        }
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn warm_start() {
+        // the sink of the top of a stack with few iterations
+        let sink = |tolerance: f64| {
+            let mut w = World::new();
+            w.iterations = 2;
+            w.contact_tolerance = tolerance;
+            w.sleep_time = f64::INFINITY;
+            let _ = ground(&mut w);
+            let top = (0..4)
+                .map(|i| {
+                    let p = v(0.0, 0.0, 0.5 + i as f64);
+                    cuboid(&mut w, p, v(0.5, 0.5, 0.5))
+                })
+                .last()
+                .unwrap();
+            run(&mut w, 3.0);
+            3.5 - w.body(top).unwrap().rigit.position()[2]
+        };
+        // no contact point is near enough to the cached ones with `0.0`
+        let (warm, cold) = (sink(0.05), sink(0.0));
+        assert!(warm < 0.02 && 0.05 < cold, "{} {}", warm, cold);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn bounce() {
+        let mut w = World::new();
+        let g = ground(&mut w);
+        w.body_mut(g).unwrap().restitution = 1.0;
+        let s = sphere(&mut w, v(0.0, 0.0, 3.5), 0.5);
+        w.body_mut(s).unwrap().restitution = 1.0;
+        let mut top: f64 = 0.0;
+        let mut bounced = false;
+        for _ in 0..120 {
+            let _ = w.step(1.0 / 60.0);
+            let b = w.body(s).unwrap();
+            if b.rigit.velocity()[2] > 0.0 {
+                bounced = true;
+            }
+            if bounced {
+                top = top.max(b.rigit.position()[2]);
+            }
+        }
+        assert!(bounced);
+        assert!(top > 3.2, "{}", top);
+
+        // no bounce without restitution
+        let mut w = World::new();
+        let _ = ground(&mut w);
+        let s = sphere(&mut w, v(0.0, 0.0, 3.5), 0.5);
+        run(&mut w, 1.5);
+        assert!(w.body(s).unwrap().rigit.position()[2] < 0.52);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn friction() {
+        let mut w = World::new();
+        let _ = ground(&mut w);
+        let c = cuboid(&mut w, v(0.0, 0.0, 0.5), v(0.5, 0.5, 0.5));
+        let _ = w.body_mut(c).unwrap().rigit.set_velocity(v(4.0, 0.0, 0.0));
+        run(&mut w, 3.0);
+        // friction 0.5 against itself, v^2 / (2 mu g)
+        let x = w.body(c).unwrap().rigit.position()[0];
+        let expect = 16.0 / (2.0 * 0.5 * -gravity::<f64>()[2]);
+        assert!((x - expect).abs() < 0.1 * expect, "{} {}", x, expect);
+        let b = w.body(c).unwrap();
+        assert!(b.rigit.angular_velocity().length() < 0.05, "{:?}", b);
+
+        // frictionless
+        let mut w = World::new();
+        let g = ground(&mut w);
+        w.body_mut(g).unwrap().friction = 0.0;
+        let c = cuboid(&mut w, v(0.0, 0.0, 0.5), v(0.5, 0.5, 0.5));
+        let _ = w.body_mut(c).unwrap().rigit.set_velocity(v(4.0, 0.0, 0.0));
+        run(&mut w, 1.0);
+        assert!((w.body(c).unwrap().rigit.velocity()[0] - 4.0).abs() < 1.0e-6);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn hull() {
+        let mut w = World::new();
+        let _ = ground(&mut w);
+        let base = cuboid(&mut w, v(0.0, 0.0, 0.5), v(1.0, 1.0, 0.5));
+        let corners: Vec<Vector3<f64>> = (0..8)
+            .map(|i| {
+                let s = |b: usize| if i & b != 0 { 0.3 } else { -0.3 };
+                v(s(1), s(2), s(4))
+            })
+            .collect();
+        let shape = Shape::Hull(ConvexHull::new(corners).unwrap());
+        let mut rigit = Rigit::new(0.5, diagonal(0.03, 0.03, 0.03)).unwrap();
+        let _ = rigit.set_position(v(0.2, -0.1, 2.0));
+        let h = w.insert(Body::new(rigit, shape).unwrap());
+        run(&mut w, 3.0);
+        let z = w.body(h).unwrap().rigit.position()[2];
+        assert!((z - 1.3).abs() < 0.03, "{}", z);
+        let z = w.body(base).unwrap().rigit.position()[2];
+        assert!((z - 0.5).abs() < 0.03, "{}", z);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn wake() {
+        let mut w = World::new();
+        let _ = ground(&mut w);
+        let a = sphere(&mut w, v(0.0, 0.0, 0.5), 0.5);
+        run(&mut w, 1.0);
+        assert!(w.body(a).unwrap().is_sleeping());
+        let b = sphere(&mut w, v(0.3, 0.0, 3.0), 0.5);
+        run(&mut w, 1.0);
+        assert!(w.body(a).unwrap().rigit.position()[0] < -0.05);
+        assert!(w.body(b).unwrap().rigit.position()[0] > 0.35);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn write_back() {
+        let mut w = World::new();
+        let _ = ground(&mut w);
+        let s = sphere(&mut w, v(1.0, 2.0, 3.0), 0.5);
+        let parent = ManagedValue::new(Node::new(Uuid::nil(), None));
+        {
+            let mut p = parent.as_ref().borrow_mut();
+            let t: &mut sif_three::TraRotSca<f64> = p.as_mut();
+            t.translate = v(0.0, 0.0, 1.0);
+            t.rotate =
+                Quaternion::from_axis_angle(&v(0.0, 0.0, 1.0), 1.0).unwrap();
+        }
+        let node =
+            ManagedValue::new(Node::new(Uuid::nil(), Some(parent.clone())));
+        {
+            let b = w.body_mut(s).unwrap();
+            b.node = Some(node.clone());
+            let _ = b.rigit.set_angular_velocity(v(1.0, 0.0, 2.0));
+        }
+        run(&mut w, 0.5);
+        w.write_back().unwrap();
+        let m = *node.as_ref().borrow_mut().update().unwrap();
+        let p = m * Vector4::new(0.0, 0.0, 0.0, 1.0);
+        let b = w.body(s).unwrap();
+        let q = *b.rigit.position();
+        assert!((v(p[0], p[1], p[2]) - q).length() < 1.0e-9, "{:?}", p);
+        assert!(1.0e-3 < (b.rigit.angle()[3] - 1.0).abs());
+        let r = Matrix3x3::from(*b.rigit.angle());
+        for i in 0..3 {
+            let c = m[i];
+            assert!((v(c[0], c[1], c[2]) - r[i]).length() < 1.0e-9, "{:?}", m);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! test_util.rs
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use sif_math::{Quaternion, Vector3};
+// ----------------------------------------------------------------------------
+use crate::geometry::Obb;
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// v
+pub(crate) fn v(x: f64, y: f64, z: f64) -> Vector3<f64> {
+    Vector3::new(x, y, z)
+}
+// ----------------------------------------------------------------------------
+/// rot
+pub(crate) fn rot(axis: Vector3<f64>, angle: f64) -> Quaternion<f64> {
+    Quaternion::from_axis_angle(&axis, angle).unwrap()
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// struct Rand
+///
+/// Xorshift, reproducible from the seed.
+#[derive(Debug, Clone)]
+pub(crate) struct Rand(u64);
+// ============================================================================
+impl Rand {
+    // ========================================================================
+    /// new
+    ///
+    /// `seed` is not zero.
+    pub(crate) fn new(seed: u64) -> Self {
+        Rand(seed)
+    }
+    // ========================================================================
+    /// next
+    ///
+    /// In `[0, 1)`.
+    pub(crate) fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+    // ------------------------------------------------------------------------
+    /// obb
+    ///
+    /// Centered in `[0, 3)^3`, the radii in `[0.2, 1.2)`, rotated at random.
+    pub(crate) fn obb(&mut self) -> Obb<f64> {
+        let axis = v(self.next() - 0.5, self.next() - 0.5, self.next() - 0.5);
+        Obb::new(
+            v(self.next() * 3.0, self.next() * 3.0, self.next() * 3.0),
+            v(0.2 + self.next(), 0.2 + self.next(), 0.2 + self.next()),
+            rot(axis, self.next() * 6.0),
+        )
+    }
+}
@@ -0,0 +1,70 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! armature.rs
+//!
+//! Measures `Armature::update` on a chain of bones, whose pose is dirtied
+//! each iteration. Run with
+//! `cargo bench -p sif_three [--features sif_three/simd]`.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// use  =======================================================================
+use std::time::Instant;
+// ----------------------------------------------------------------------------
+use uuid::Uuid;
+// ----------------------------------------------------------------------------
+use sif_math::{Float, Quaternion, Vector3};
+use sif_three::{Armature, Bone, Pose};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+/// BONES
+const BONES: usize = 256;
+/// ITERATIONS
+const ITERATIONS: u32 = 2000;
+// ============================================================================
+/// bench
+fn bench<V: Float>(name: &str) {
+    let one = V::one();
+    let bones = (0..BONES)
+        .map(|i| {
+            Bone::new(
+                format!("bone{}", i),
+                Vector3::new(V::zero(), one, V::zero()),
+                if i == 0 { None } else { Some(i - 1) },
+            )
+        })
+        .collect::<Vec<_>>();
+    let armature = Armature::new(Uuid::nil(), "chain", bones);
+    let mut pose = Pose::<V>::new(BONES);
+    let axis = Vector3::new(V::zero(), V::zero(), one);
+    let angle = V::from(0.01).unwrap();
+
+    let start = Instant::now();
+    for n in 0..ITERATIONS {
+        let q =
+            Quaternion::from_axis_angle(&axis, angle * V::from(n).unwrap())
+                .unwrap();
+        for trs in pose.iter_local_mut() {
+            trs.rotate = q;
+        }
+        let _ = armature.update(&mut pose).unwrap();
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "armature::update<{}> {} bones: {:>10} ns/iter",
+        name,
+        BONES,
+        elapsed.as_nanos() / u128::from(ITERATIONS)
+    );
+}
+// ============================================================================
+fn main() {
+    bench::<f32>("f32");
+    bench::<f64>("f64");
+}
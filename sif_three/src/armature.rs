@@ -6,13 +6,15 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/23
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // mod  =======================================================================
 mod bone;
 mod pose;
 // use  =======================================================================
+use alloc::{string::String, vec::Vec};
+// ----------------------------------------------------------------------------
 use uuid::Uuid;
 // ----------------------------------------------------------------------------
 use sif_math::Float;
@@ -39,6 +41,16 @@ where
     bones: Vec<Bone<V>>,
 }
 // ============================================================================
+impl From<Armature<f32>> for Armature<f64> {
+    fn from(src: Armature<f32>) -> Self {
+        Armature {
+            uuid: src.uuid,
+            name: src.name,
+            bones: src.bones.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+// ============================================================================
 impl<V> Armature<V>
 where
     V: Float,
@@ -57,6 +69,21 @@ where
             bones,
         }
     }
+    // ------------------------------------------------------------------------
+    /// cast
+    ///
+    /// `None` if a component is out of the range of `U`.
+    pub fn cast<U: Float>(&self) -> Option<Armature<U>> {
+        Some(Armature {
+            uuid: self.uuid,
+            name: self.name.clone(),
+            bones: self
+                .bones
+                .iter()
+                .map(|b| b.cast())
+                .collect::<Option<_>>()?,
+        })
+    }
     // ========================================================================
     /// fn len
     pub fn len(&self) -> usize {
@@ -151,3 +178,103 @@ where
         &mut self.bones
     }
 }
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use sif_math::{Quaternion, Vector3};
+    // ------------------------------------------------------------------------
+    use super::*;
+    use crate::TraRotSca;
+    use alloc::vec;
+    // ========================================================================
+    fn pose() -> Pose<f32> {
+        let mut pose = Pose::new(2);
+        pose[1] = TraRotSca::new(
+            Vector3::new(1.0, 2.0, 3.0),
+            Quaternion::new(0.0, 0.6, 0.0, 0.8),
+            Vector3::new(1.0, 1.0, 2.0),
+        );
+        pose
+    }
+    // ------------------------------------------------------------------------
+    fn same(a: &Pose<f32>, b: &Pose<f32>) -> bool {
+        a.iter_local().zip(b.iter_local()).all(|(x, y)| {
+            x.translate == y.translate
+                && x.rotate == y.rotate
+                && x.scale == y.scale
+        }) && a.len() == b.len()
+    }
+    // ========================================================================
+    #[test]
+    fn cast() {
+        let armature = Armature::new(
+            Uuid::nil(),
+            "armature",
+            vec![
+                Bone::new("root", Vector3::new(0.0f64, 0.0, 0.0), None),
+                Bone::new("arm", Vector3::new(0.0, 1.5, 0.0), Some(0)),
+            ],
+        );
+        let a = armature.cast::<f32>().unwrap();
+        let mut p = pose();
+        let _ = a.update(&mut p).unwrap();
+        let mut q = Pose::<f64>::from(p.clone());
+        let _ = Armature::<f64>::from(a).update(&mut q).unwrap();
+        assert!(same(&p, &q.cast().unwrap()));
+        assert_eq!(q.matrix[1][3][1], 2.0);
+        assert_eq!(p.cast::<f64>().unwrap().matrix[1], q.matrix[1]);
+
+        q[1].translate = Vector3::new(1.0e300, 0.0, 0.0);
+        assert!(q.cast::<f32>().is_none());
+        let big = Bone::new("big", Vector3::new(1.0e300f64, 0.0, 0.0), None);
+        assert!(big.cast::<f32>().is_none());
+        assert!(Armature::new(Uuid::nil(), "", vec![big])
+            .cast::<f32>()
+            .is_none());
+    }
+    // ========================================================================
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json() {
+        let json = serde_json::to_string(&pose()).unwrap();
+        assert_eq!(
+            json,
+            "[[[0.0,0.0,0.0],[0.0,0.0,0.0,1.0],[1.0,1.0,1.0]],\
+             [[1.0,2.0,3.0],[0.0,0.6,0.0,0.8],[1.0,1.0,2.0]]]"
+        );
+        assert!(same(&pose(), &serde_json::from_str(&json).unwrap()));
+
+        let bone = Bone::new("arm", Vector3::new(0.0f32, 1.0, 0.0), Some(0));
+        let json = serde_json::to_string(&bone).unwrap();
+        assert_eq!(json, "[\"arm\",[0.0,1.0,0.0],0]");
+        let b: Bone<f32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(AsRef::<str>::as_ref(&b), "arm");
+        assert_eq!(b.parent, Some(0));
+    }
+    // ------------------------------------------------------------------------
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_bincode() {
+        let bin = bincode::serialize(&pose()).unwrap();
+        let mut p: Pose<f32> = bincode::deserialize(&bin).unwrap();
+        assert!(same(&pose(), &p));
+        assert!(p.flags.iter().all(|f| f.contains(PoseFlags::DIRTY)));
+
+        let armature = Armature::new(
+            Uuid::nil(),
+            "armature",
+            bincode::deserialize::<Vec<Bone<f32>>>(
+                &bincode::serialize(&vec![
+                    Bone::new("root", Vector3::new(0.0f32, 0.0, 0.0), None),
+                    Bone::new("arm", Vector3::new(0.0, 1.0, 0.0), Some(0)),
+                ])
+                .unwrap(),
+            )
+            .unwrap(),
+        );
+        let _ = armature.update(&mut p).unwrap();
+        assert_eq!(p.matrix[1][3][0], 1.0);
+    }
+}
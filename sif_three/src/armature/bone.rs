@@ -6,12 +6,17 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/19
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
+use alloc::string::String;
+// ----------------------------------------------------------------------------
 use sif_math::{Float, Matrix4x4, Vector3};
 // ----------------------------------------------------------------------------
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+// ----------------------------------------------------------------------------
 use super::super::new_mat4_tra;
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
@@ -46,6 +51,17 @@ where
             parent,
         }
     }
+    // ------------------------------------------------------------------------
+    /// cast
+    ///
+    /// `None` if a component is out of the range of `U`.
+    pub fn cast<U: Float>(&self) -> Option<Bone<U>> {
+        Some(Bone {
+            name: self.name.clone(),
+            offset: self.offset.cast()?,
+            parent: self.parent,
+        })
+    }
     // ========================================================================
     /// offset_matrix
     pub fn offset_matrix(&self) -> Matrix4x4<V> {
@@ -57,6 +73,50 @@ where
         new_mat4_tra::<V>(-self.offset[0], -self.offset[1], -self.offset[2])
     }
 }
+// ----------------------------------------------------------------------------
+impl From<Bone<f32>> for Bone<f64> {
+    fn from(src: Bone<f32>) -> Self {
+        Bone {
+            name: src.name,
+            offset: src.offset.into(),
+            parent: src.parent,
+        }
+    }
+}
+// ============================================================================
+#[cfg(feature = "serde")]
+impl<V> Serialize for Bone<V>
+where
+    V: Float + Serialize,
+{
+    /// `[name, offset, parent]`
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> ::core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (&self.name, &self.offset, &self.parent).serialize(serializer)
+    }
+}
+// ----------------------------------------------------------------------------
+#[cfg(feature = "serde")]
+impl<'de, V> Deserialize<'de> for Bone<V>
+where
+    V: Float + Deserialize<'de>,
+{
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (name, offset, parent): (String, _, _) =
+            Deserialize::deserialize(deserializer)?;
+        Ok(Bone::new(name, offset, parent))
+    }
+}
 // ============================================================================
 impl<V> AsRef<str> for Bone<V>
 where
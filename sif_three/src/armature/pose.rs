@@ -6,16 +6,19 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/02/25
-//  @date 2020/08/28
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
-use std::{
+use alloc::{vec, vec::Vec};
+use core::{
     iter::{Enumerate, Iterator},
     slice::{Iter, IterMut},
 };
 // ----------------------------------------------------------------------------
 use bitflags::bitflags;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 // ----------------------------------------------------------------------------
 use sif_math::{Float, Matrix4x4};
 // ----------------------------------------------------------------------------
@@ -54,7 +57,56 @@ where
     pub flags: Vec<Flags>,
 }
 // ============================================================================
-impl<V> ::std::ops::Index<usize> for Pose<V>
+#[cfg(feature = "serde")]
+impl<V> Serialize for Pose<V>
+where
+    V: Float + Serialize,
+{
+    /// `[local, ...]`
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> ::core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.local.serialize(serializer)
+    }
+}
+// ----------------------------------------------------------------------------
+#[cfg(feature = "serde")]
+impl<'de, V> Deserialize<'de> for Pose<V>
+where
+    V: Float + Deserialize<'de>,
+{
+    /// Every bone is dirty, so `Armature::update` recomputes the matrices.
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let local = Vec::<TraRotSca<V>>::deserialize(deserializer)?;
+        let len = local.len();
+        Ok(Pose {
+            matrix: vec![Matrix4x4::<V>::default(); len],
+            local,
+            flags: vec![Flags::default(); len],
+        })
+    }
+}
+// ============================================================================
+impl From<Pose<f32>> for Pose<f64> {
+    fn from(src: Pose<f32>) -> Self {
+        Pose {
+            matrix: src.matrix.into_iter().map(Into::into).collect(),
+            local: src.local.into_iter().map(Into::into).collect(),
+            flags: src.flags,
+        }
+    }
+}
+// ============================================================================
+impl<V> ::core::ops::Index<usize> for Pose<V>
 where
     V: Float,
 {
@@ -64,7 +116,7 @@ where
     }
 }
 // ----------------------------------------------------------------------------
-impl<V> ::std::ops::IndexMut<usize> for Pose<V>
+impl<V> ::core::ops::IndexMut<usize> for Pose<V>
 where
     V: Float,
 {
@@ -87,6 +139,25 @@ where
             flags: vec![Flags::default(); len],
         }
     }
+    // ------------------------------------------------------------------------
+    /// cast
+    ///
+    /// `None` if a component is out of the range of `U`. The flags are kept.
+    pub fn cast<U: Float>(&self) -> Option<Pose<U>> {
+        Some(Pose {
+            matrix: self
+                .matrix
+                .iter()
+                .map(|m| m.cast())
+                .collect::<Option<_>>()?,
+            local: self
+                .local
+                .iter()
+                .map(|t| t.cast())
+                .collect::<Option<_>>()?,
+            flags: self.flags.clone(),
+        })
+    }
     // ========================================================================
     /// fn len
     pub fn len(&self) -> usize {
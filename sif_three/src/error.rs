@@ -6,10 +6,12 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/12/10
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
+use alloc::string::String;
+#[cfg(feature = "std")]
 use std::error::Error as StdError;
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
@@ -30,9 +32,10 @@ pub enum Error {
     SifManager(::sif_manager::Error),
 }
 // ============================================================================
-impl From<::std::io::Error> for Error {
-    fn from(e: ::std::io::Error) -> Self {
-        Error::IO(format!("{}", e))
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(alloc::format!("{}", e))
     }
 }
 // ----------------------------------------------------------------------------
@@ -42,13 +45,14 @@ impl From<::sif_manager::Error> for Error {
     }
 }
 // ============================================================================
-impl ::std::fmt::Display for Error {
+impl ::core::fmt::Display for Error {
     // ========================================================================
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        <Self as ::std::fmt::Debug>::fmt(self, f)
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        <Self as ::core::fmt::Debug>::fmt(self, f)
     }
 }
 // ============================================================================
+#[cfg(feature = "std")]
 impl StdError for Error {
     // ========================================================================
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
@@ -65,7 +69,7 @@ impl StdError for Error {
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 /// type Result
-pub type Result<T> = ::std::result::Result<T, Error>;
+pub type Result<T> = ::core::result::Result<T, Error>;
 // ////////////////////////////////////////////////////////////////////////////
 // ============================================================================
 #[cfg(test)]
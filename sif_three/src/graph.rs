@@ -6,13 +6,14 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/05/23
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // mod ========================================================================
 mod node;
 // use  =======================================================================
-use std::{borrow::Borrow, hash::Hash, iter::IntoIterator};
+use alloc::format;
+use core::{borrow::Borrow, hash::Hash, iter::IntoIterator};
 // ----------------------------------------------------------------------------
 use uuid::Uuid;
 // ----------------------------------------------------------------------------
@@ -6,11 +6,12 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2016/02/25
-//  @date 2020/03/19
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
-use std::{cell::RefCell, fmt::Debug};
+use alloc::string::ToString;
+use core::{cell::RefCell, fmt::Debug};
 // ----------------------------------------------------------------------------
 use bitflags::bitflags;
 use uuid::Uuid;
@@ -6,7 +6,7 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2018/05/12
-//  @date 2020/08/28
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // attribute  =================================================================
@@ -123,6 +123,11 @@
     unknown_crate_types
 )]
 #![warn(unsafe_code, dead_code, renamed_and_removed_lints, unreachable_pub)]
+#![no_std]
+// extern  ====================================================================
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
 // mod  =======================================================================
 mod armature;
 mod error;
@@ -6,11 +6,20 @@
 //  @author hanepjiv <hanepjiv@gmail.com>
 //  @copyright The MIT License (MIT) / Apache License Version 2.0
 //  @since 2017/04/10
-//  @date 2018/08/27
+//  @date 2026/10/18
 
 // ////////////////////////////////////////////////////////////////////////////
 // use  =======================================================================
-use sif_math::{Float, Matrix4x4, Quaternion, Vector3, Vector4};
+use alloc::string::String;
+use core::ops::Mul;
+// ----------------------------------------------------------------------------
+use sif_math::{
+    lerp, nlerp, slerp, DualQuaternion, EulerOrder, Float, Matrix3x3,
+    Matrix4x4, Quaternion, Vector3, Vector4,
+};
+// ----------------------------------------------------------------------------
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 // ----------------------------------------------------------------------------
 use super::{Error, Result};
 // ////////////////////////////////////////////////////////////////////////////
@@ -56,6 +65,71 @@ where
     }
 }
 // ============================================================================
+#[cfg(feature = "serde")]
+impl<V> Serialize for TraRotSca<V>
+where
+    V: Float + Serialize,
+{
+    /// `[translate, rotate, scale]`
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> ::core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (&self.translate, &self.rotate, &self.scale).serialize(serializer)
+    }
+}
+// ----------------------------------------------------------------------------
+#[cfg(feature = "serde")]
+impl<'de, V> Deserialize<'de> for TraRotSca<V>
+where
+    V: Float + Deserialize<'de>,
+{
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (translate, rotate, scale) =
+            Deserialize::deserialize(deserializer)?;
+        Ok(TraRotSca::new(translate, rotate, scale))
+    }
+}
+// ============================================================================
+impl<V> From<TraRotSca<V>> for DualQuaternion<V>
+where
+    V: Float,
+{
+    /// The scale is dropped, since a dual quaternion is a rigid transform.
+    fn from(trs: TraRotSca<V>) -> Self {
+        trs.dual_quaternion()
+    }
+}
+// ============================================================================
+impl<V> Mul for TraRotSca<V>
+where
+    V: Float,
+{
+    type Output = Self;
+    /// `rhs` first, then `self`. See `compose`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+// ----------------------------------------------------------------------------
+impl From<TraRotSca<f32>> for TraRotSca<f64> {
+    fn from(src: TraRotSca<f32>) -> Self {
+        TraRotSca::new(
+            src.translate.into(),
+            src.rotate.into(),
+            src.scale.into(),
+        )
+    }
+}
+// ============================================================================
 impl<V> TraRotSca<V>
 where
     V: Float,
@@ -73,6 +147,192 @@ where
             scale,
         }
     }
+    // ------------------------------------------------------------------------
+    /// new_euler
+    pub fn new_euler(
+        translate: Vector3<V>,
+        angles: &Vector3<V>,
+        order: EulerOrder,
+        scale: Vector3<V>,
+    ) -> Self {
+        TraRotSca {
+            rotate: Quaternion::from_euler(angles, order),
+            translate,
+            scale,
+        }
+    }
+    // ------------------------------------------------------------------------
+    /// from_matrix
+    ///
+    /// Decompose an affine matrix. A negative determinant is taken as the
+    /// negative scale of X.
+    /// `Err` if `m` is not affine, is singular or has a shear.
+    /// The tolerances are relative to the largest elements of `m` and of
+    /// its linear part, as `Matrix::new_lu` does.
+    pub fn from_matrix(m: &Matrix4x4<V>) -> Result<Self> {
+        let (mut norm, mut linear) = (V::zero(), V::zero());
+        for i in 0..4 {
+            for j in 0..4 {
+                norm = norm.max(m[i][j].abs());
+                if i < 3 && j < 3 {
+                    linear = linear.max(m[i][j].abs());
+                }
+            }
+        }
+        let tol = V::epsilon().sqrt();
+        let affine = tol * norm;
+        let singular = linear * V::epsilon() * V::from(3).unwrap();
+        let err = |s: &str| {
+            Err(Error::InvalidArgument(
+                String::from(
+                    "::sif_three::trarotsca::TraRotSca::from_matrix: ",
+                ) + s,
+            ))
+        };
+        // m[col][row]
+        if affine < m[0][3].abs()
+            || affine < m[1][3].abs()
+            || affine < m[2][3].abs()
+            || affine < (m[3][3] - V::one()).abs()
+        {
+            return err("not affine");
+        }
+        let mut c = [
+            Vector3::from(m[0]),
+            Vector3::from(m[1]),
+            Vector3::from(m[2]),
+        ];
+        let mut scale =
+            Vector3::new(c[0].length(), c[1].length(), c[2].length());
+        for (i, v) in c.iter_mut().enumerate() {
+            if scale[i] <= singular {
+                return err("singular");
+            }
+            *v /= scale[i];
+        }
+        if tol < c[0].dot(&c[1]).abs()
+            || tol < c[1].dot(&c[2]).abs()
+            || tol < c[2].dot(&c[0]).abs()
+        {
+            return err("shear");
+        }
+        if c[0].dot(&c[1].cross(&c[2])) < V::zero() {
+            scale[0] = -scale[0];
+            c[0] *= -V::one();
+        }
+        Ok(TraRotSca::new(
+            Vector3::from(m[3]),
+            Quaternion::from(Matrix3x3::from(c)),
+            scale,
+        ))
+    }
+    // ------------------------------------------------------------------------
+    /// cast
+    ///
+    /// `None` if a component is out of the range of `U`.
+    pub fn cast<U: Float>(&self) -> Option<TraRotSca<U>> {
+        Some(TraRotSca::new(
+            self.translate.cast()?,
+            self.rotate.cast()?,
+            self.scale.cast()?,
+        ))
+    }
+    // ========================================================================
+    /// euler
+    pub fn euler(&self, order: EulerOrder) -> Vector3<V> {
+        self.rotate.to_euler(order)
+    }
+    // ------------------------------------------------------------------------
+    /// set_euler
+    pub fn set_euler(
+        &mut self,
+        angles: &Vector3<V>,
+        order: EulerOrder,
+    ) -> &mut Self {
+        self.rotate = Quaternion::from_euler(angles, order);
+        self
+    }
+    // ========================================================================
+    /// compose
+    ///
+    /// The transform applying `rhs` first, then `self`, as
+    /// `self.matrix() * rhs.matrix()`. Exact while the scale of `self` is
+    /// uniform, otherwise the shear that would arise is dropped.
+    pub fn compose(&self, rhs: &Self) -> Self {
+        TraRotSca::new(
+            self.transform_point(&rhs.translate),
+            *(self.rotate * rhs.rotate).normalize(),
+            self.scale.mul_elem(&rhs.scale),
+        )
+    }
+    // ------------------------------------------------------------------------
+    /// inverse
+    ///
+    /// Exact while the scale is uniform, like `compose`. `inverse_matrix` is
+    /// always exact.
+    /// `Err` if a scale is zero, relative to the largest.
+    pub fn inverse(&self) -> Result<Self> {
+        let max = self.scale.iter().fold(V::zero(), |m, s| m.max(s.abs()));
+        if self.scale.iter().any(|s| s.abs() <= max * V::epsilon()) {
+            return Err(Error::InvalidArgument(String::from(
+                "::sif_three::trarotsca::TraRotSca::inverse",
+            )));
+        }
+        let rotate = self.rotate.conjugate();
+        let scale = Vector3::new(
+            V::one() / self.scale[0],
+            V::one() / self.scale[1],
+            V::one() / self.scale[2],
+        );
+        let translate = scale.mul_elem(&rotate.rotate(&self.translate));
+        Ok(TraRotSca::new(translate * -V::one(), rotate, scale))
+    }
+    // ========================================================================
+    /// transform_point
+    ///
+    /// The same as `matrix() * (p, 1)`.
+    pub fn transform_point(&self, p: &Vector3<V>) -> Vector3<V> {
+        self.translate + self.transform_direction(p)
+    }
+    // ------------------------------------------------------------------------
+    /// transform_direction
+    ///
+    /// The same as `matrix() * (d, 0)`, scaled but not translated.
+    pub fn transform_direction(&self, d: &Vector3<V>) -> Vector3<V> {
+        self.rotate.rotate(&self.scale.mul_elem(d))
+    }
+    // ========================================================================
+    /// lerp
+    ///
+    /// Linear on the translate and the scale, `nlerp` on the rotate.
+    pub fn lerp(&self, rhs: &Self, t: V) -> Self {
+        TraRotSca::new(
+            lerp(self.translate, rhs.translate, t),
+            nlerp(&self.rotate, &rhs.rotate, t),
+            lerp(self.scale, rhs.scale, t),
+        )
+    }
+    // ------------------------------------------------------------------------
+    /// slerp
+    ///
+    /// Linear on the translate and the scale, `slerp` on the rotate.
+    pub fn slerp(&self, rhs: &Self, t: V) -> Self {
+        TraRotSca::new(
+            lerp(self.translate, rhs.translate, t),
+            slerp(&self.rotate, &rhs.rotate, t),
+            lerp(self.scale, rhs.scale, t),
+        )
+    }
+    // ========================================================================
+    /// dual_quaternion
+    ///
+    /// The scale is dropped.
+    pub fn dual_quaternion(&self) -> DualQuaternion<V> {
+        DualQuaternion::from_rotation_translation(
+            &self.rotate,
+            &self.translate,
+        )
+    }
     // ========================================================================
     /// matrix
     pub fn matrix(&self) -> Matrix4x4<V> {
@@ -258,16 +518,162 @@ pub fn new_quaternion_rot<V>(
 where
     V: Float,
 {
-    // let pi = ::num::cast::cast::<_, V>(::std::f64::consts::PI).unwrap();
-    let l = (x * x) + (y * y) + (z * z);
-    if l < V::epsilon() {
-        Err(Error::InvalidArgument(String::from(
+    Quaternion::from_axis_angle(&Vector3::new(x, y, z), theta).map_err(|_| {
+        Error::InvalidArgument(String::from(
             "::three::trarotsca::::new_quaternion_rot",
-        )))
-    } else {
-        let (mut sin, cos) =
-            ::num::Float::sin_cos(theta / V::from(2).unwrap());
-        sin /= ::num::Float::sqrt(l);
-        Ok(Quaternion::from([x * sin, y * sin, z * sin, cos]))
+        ))
+    })
+}
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    // use  ===================================================================
+    use super::*;
+    use sif_math::assert_approx_eq;
+    // ========================================================================
+    fn trs(
+        t: [f64; 3],
+        axis: [f64; 3],
+        angle: f64,
+        s: [f64; 3],
+    ) -> TraRotSca<f64> {
+        TraRotSca::new(
+            Vector3::from(t),
+            Quaternion::from_axis_angle(&Vector3::from(axis), angle).unwrap(),
+            Vector3::from(s),
+        )
+    }
+    // ========================================================================
+    #[test]
+    fn cast() {
+        let a = trs([1.0, -2.0, 0.5], [0.0, 1.0, 1.0], 0.8, [1.0, 2.0, 0.5]);
+        let b = a.cast::<f32>().unwrap();
+        assert_eq!(b.scale, Vector3::new(1.0f32, 2.0, 0.5));
+        let c = TraRotSca::<f64>::from(b);
+        assert!((c.translate - a.translate).length() < 1.0e-6);
+        assert!((c.rotate - a.rotate).length() < 1.0e-6);
+        let mut big = a;
+        big.scale = Vector3::new(1.0, 1.0e300, 1.0);
+        assert!(big.cast::<f32>().is_none());
+    }
+    // ========================================================================
+    #[test]
+    fn compose() {
+        let a = trs([1.0, 2.0, 3.0], [0.0, 1.0, 1.0], 0.7, [2.0, 2.0, 2.0]);
+        let b = trs([-3.0, 0.5, 1.0], [1.0, 0.0, 0.5], -1.2, [1.0, 3.0, 0.5]);
+        let ab = a.clone() * b.clone();
+        assert_approx_eq!(
+            ab.matrix(),
+            a.matrix() * b.matrix(),
+            epsilon = 1.0e-12
+        );
+        let p = Vector3::new(0.25, -4.0, 2.0);
+        let m = a.matrix() * Vector4::from_vector3(&p, 1.0);
+        assert_approx_eq!(
+            a.transform_point(&p),
+            Vector3::from(m),
+            epsilon = 1.0e-12
+        );
+        let m = a.matrix() * Vector4::from_vector3(&p, 0.0);
+        assert_approx_eq!(
+            a.transform_direction(&p),
+            Vector3::from(m),
+            epsilon = 1.0e-12
+        );
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn inverse() {
+        let a = trs([1.0, 2.0, 3.0], [0.0, 1.0, 1.0], 0.7, [2.0, 2.0, 2.0]);
+        let i = a.inverse().unwrap();
+        assert_approx_eq!(i.matrix(), a.inverse_matrix(), epsilon = 1.0e-12);
+        let p = Vector3::new(0.25, -4.0, 2.0);
+        assert_approx_eq!(
+            i.transform_point(&a.transform_point(&p)),
+            p,
+            epsilon = 1.0e-12
+        );
+        // the translate is exact even for a non uniform scale
+        let b = trs([-3.0, 0.5, 1.0], [1.0, 0.0, 0.5], -1.2, [1.0, 3.0, 0.5]);
+        let m = b.inverse_matrix();
+        assert_approx_eq!(
+            b.inverse().unwrap().translate,
+            Vector3::from(m[3]),
+            epsilon = 1.0e-12
+        );
+        assert!(trs([0.0; 3], [1.0, 0.0, 0.0], 0.0, [1.0, 0.0, 1.0])
+            .inverse()
+            .is_err());
+        assert!(trs([0.0; 3], [1.0, 0.0, 0.0], 0.0, [0.0; 3])
+            .inverse()
+            .is_err());
+        // a small scale that from_matrix accepts is invertible
+        let mut m = TraRotSca::new(
+            Vector3::new(0.5f32, 0.0, 0.0),
+            Quaternion::from_axis_angle(&Vector3::new(0.0, 0.0, 1.0), 0.5)
+                .unwrap(),
+            Vector3::new(1.0, 1.0, 1.0),
+        )
+        .matrix();
+        for i in 0..3 {
+            for j in 0..3 {
+                m[i][j] *= 1.0e-8;
+            }
+        }
+        let c = TraRotSca::from_matrix(&m).unwrap();
+        assert!((c.scale[0] - 1.0e-8).abs() < 1.0e-13, "{:?}", c);
+        let i = c.inverse().unwrap();
+        assert!((i.scale[0] - 1.0e8).abs() < 1.0e3, "{:?}", i);
+        let p = Vector3::new(0.25f32, -4.0, 2.0);
+        let q = c.transform_point(&i.transform_point(&p));
+        assert!((q - p).length() < 1.0e-5, "{:?}", q);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn interpolate() {
+        let a = trs([0.0, 0.0, 0.0], [0.0, 0.0, 1.0], 0.2, [1.0, 1.0, 1.0]);
+        let b = trs([2.0, 4.0, 0.0], [0.0, 0.0, 1.0], 1.0, [3.0, 1.0, 1.0]);
+        let h = a.slerp(&b, 0.5);
+        assert_approx_eq!(h.translate, Vector3::new(1.0, 2.0, 0.0));
+        assert_approx_eq!(h.scale, Vector3::new(2.0, 1.0, 1.0));
+        let r = Quaternion::from_axis_angle(&Vector3::new(0.0, 0.0, 1.0), 0.6)
+            .unwrap();
+        assert_approx_eq!(h.rotate, r, epsilon = 1.0e-12);
+        assert_approx_eq!(a.lerp(&b, 0.5).rotate, r, epsilon = 1.0e-12);
+        assert_approx_eq!(a.lerp(&b, 1.0).rotate, b.rotate, epsilon = 1.0e-12);
+    }
+    // ------------------------------------------------------------------------
+    #[test]
+    fn from_matrix() {
+        let a = trs([1.0, 2.0, 3.0], [0.3, 1.0, -1.0], 2.5, [2.0, 0.5, 4.0]);
+        let d = TraRotSca::from_matrix(&a.matrix()).unwrap();
+        assert_approx_eq!(d.translate, a.translate, epsilon = 1.0e-12);
+        assert_approx_eq!(d.scale, a.scale, epsilon = 1.0e-12);
+        assert!((d.rotate.dot(&a.rotate).abs() - 1.0).abs() < 1.0e-12);
+        // a mirror
+        let m = trs([0.0; 3], [0.0, 1.0, 0.0], 0.5, [-1.0, 2.0, 3.0]).matrix();
+        let d = TraRotSca::from_matrix(&m).unwrap();
+        assert_approx_eq!(d.matrix(), m, epsilon = 1.0e-12);
+        // a shear
+        let mut m = a.matrix();
+        m[1] = m[1] + m[0] * 0.5;
+        assert!(TraRotSca::from_matrix(&m).is_err());
+        // a projection
+        let mut m = a.matrix();
+        m[2][3] = -1.0;
+        assert!(TraRotSca::from_matrix(&m).is_err());
+        // a small scale is not singular
+        let a = TraRotSca::new(
+            Vector3::new(0.5f32, 0.0, 0.0),
+            Quaternion::from_axis_angle(&Vector3::new(0.0, 0.0, 1.0), 0.5)
+                .unwrap(),
+            Vector3::new(1.0e-4, 2.0e-4, 1.0e-4),
+        );
+        let d = TraRotSca::from_matrix(&a.matrix()).unwrap();
+        assert!((d.scale - a.scale).length() < 1.0e-9);
+        let mut m = a.matrix();
+        m[1] = Vector4::new(0.0, 0.0, 0.0, 0.0);
+        assert!(TraRotSca::from_matrix(&m).is_err());
     }
 }
@@ -0,0 +1,63 @@
+// -*- mode:rust; coding:utf-8-unix; -*-
+
+//! no_std.rs
+//!
+//! Uses the crate from a `#![no_std]` crate.
+//! `cargo test --no-default-features` builds `sif_three` itself without std.
+
+//  Copyright 2026 hanepjiv
+//  @author hanepjiv <hanepjiv@gmail.com>
+//  @copyright The MIT License (MIT) / Apache License Version 2.0
+//  @since 2026/10/18
+//  @date 2026/10/18
+
+// ////////////////////////////////////////////////////////////////////////////
+// attribute  =================================================================
+#![no_std]
+// extern  ====================================================================
+extern crate alloc;
+// use  =======================================================================
+use alloc::vec;
+// ----------------------------------------------------------------------------
+use uuid::Uuid;
+// ----------------------------------------------------------------------------
+use sif_math::{Vector3, Vector4};
+use sif_three::{Armature, Bone, Graph, Pose, TraRotSca};
+// ////////////////////////////////////////////////////////////////////////////
+// ============================================================================
+#[test]
+fn graph() {
+    let mut graph = Graph::<f32>::new(Uuid::from_u128(1)).unwrap();
+    let child = Uuid::from_u128(2);
+    let _ = graph.emplace(child, None).unwrap();
+    AsMut::<TraRotSca<f32>>::as_mut(
+        &mut *graph.root().as_ref().borrow_mut(),
+    )
+    .translate = Vector3::new(1.0, 2.0, 3.0);
+    graph.update();
+    let node = graph.get(&child).unwrap();
+    let m = *node.as_ref().borrow().as_matrix();
+    assert_eq!(
+        m * Vector4::new(0.0, 0.0, 0.0, 1.0),
+        Vector4::new(1.0, 2.0, 3.0, 1.0)
+    );
+}
+// ----------------------------------------------------------------------------
+#[test]
+fn armature() {
+    let armature = Armature::new(
+        Uuid::nil(),
+        "arm",
+        vec![
+            Bone::new("root", Vector3::new(0.0f32, 0.0, 0.0), None),
+            Bone::new("hand", Vector3::new(0.0, 1.0, 0.0), Some(0)),
+        ],
+    );
+    let mut pose = Pose::new(2);
+    pose[0].translate = Vector3::new(0.0, 0.0, 2.0);
+    let _ = armature.update(&mut pose).unwrap();
+    assert_eq!(
+        pose.matrix[1] * Vector4::new(0.0, 1.0, 0.0, 1.0),
+        Vector4::new(0.0, 1.0, 2.0, 1.0)
+    );
+}